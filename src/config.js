@@ -0,0 +1,26 @@
+const { loadConfig, initVerifier } = require('./verifier')
+
+// Configuration is resolved & validated by verifier, from TOML file
+// ( passed using `--config` ), environment variables & command line flags
+const { config, error } = loadConfig(process.argv.slice(2))
+
+if (error) {
+    console.error(`❌ Invalid configuration : ${error}`)
+    process.exit(1)
+}
+
+// Storage & public parameters are set up only once configuration is
+// known to be valid
+const { error: initError } = initVerifier()
+if (initError) {
+    console.error(`❌ Failed to initialise verifier : ${initError}`)
+    process.exit(1)
+}
+
+// Messages below configured log level are silenced
+const levels = ['error', 'warn', 'info', 'debug', 'trace']
+if (levels.indexOf(config.log_level) < levels.indexOf('info')) {
+    console.log = _ => { }
+}
+
+module.exports = config
@@ -1,17 +1,32 @@
 const humanizeDuration = require('humanize-duration')
-const { verifyProof } = require('./verifier')
-const { generateRandomDataMatrixIndices, getRows, getColumns } = require('./utils')
+const { verifyProofWithData, verifyMultiproof, fetchFromPeers, announceUnavailable, nextAlert, lookupProofs, appCells, decodeAppData, importHeader, canonicalHash, initAuthoritySet, verifyJustification, observeProofFetch, setBacklogSize, setLatestVerified } = require('./verifier')
+const { generateRandomDataMatrixIndices, getRows, getColumns, cellKey } = require('./utils')
+const { SamplingPolicy } = require('./policy')
+
+const config = require('./config')
+
+// When parent of newly seen header is unknown, at max these many
+// ancestors are fetched from full node, for linking it to header chain
+const MaxBackfill = 64
 
 class LightClient {
 
-    constructor(api_, state_) {
-        this.api = api_
+    constructor(nodes_, state_) {
+        this.nodes = nodes_
+        this.api = nodes_[0].api
         this.state = state_
+        this.policy = new SamplingPolicy()
+        // blocks which are being sampled, at this moment
+        this.backlog = 0
     }
 
     // Asking for batch proof i.e. given block number & a set of
     // data matrix indices
     //
+    // Cells are spread across all configured full nodes in round robin
+    // fashion, so that no single node serves all proofs for a block,
+    // responses are stitched back together in order of `indices`
+    //
     // @note Length of response byte array will be : len(indices) * 80
     //
     // We asked for N-many cell's proof(s) in a batch
@@ -20,8 +35,35 @@ class LightClient {
 
             try {
 
-                const proof = await this.api.rpc.kate.queryProof(blockNumber, indices)
-                res([...proof])
+                const batches = this.nodes.map(_ => [])
+                indices.forEach((_, i) => batches[i % this.nodes.length].push(i))
+
+                const parts = await Promise.all(batches.map(async (batch, i) => {
+                    if (batch.length == 0) {
+                        return []
+                    }
+
+                    const start = new Date().getTime()
+                    const part = [...await this.nodes[i].api.rpc.kate.queryProof(blockNumber, batch.map(j => indices[j]))]
+                    observeProofFetch(new Date().getTime() - start)
+
+                    if (part.length != batch.length * 80) {
+                        throw Error(`${this.nodes[i].uri} responded with ${part.length} bytes, expected ${batch.length * 80}`)
+                    }
+
+                    return part
+                }))
+
+                const proof = new Array(indices.length * 80)
+                batches.forEach((batch, i) => {
+                    batch.forEach((j, k) => {
+                        for (let b = 0; b < 80; b++) {
+                            proof[j * 80 + b] = parts[i][k * 80 + b]
+                        }
+                    })
+                })
+
+                res(proof)
 
             } catch (e) {
                 rej(e)
@@ -30,23 +72,296 @@ class LightClient {
         })
     }
 
+    // Asks for single multiproof of cells of one row, at given columns,
+    // full nodes are picked in round robin fashion, by row
+    //
+    // @note Length of response byte array will be : 96 + len(cols) * 32
+    async askMultiProof(blockNumber, row, cols) {
+        const node = this.nodes[row % this.nodes.length]
+
+        const start = new Date().getTime()
+        const proof = [...await node.api.rpc.kate.queryMultiProof(blockNumber, row, cols)]
+        observeProofFetch(new Date().getTime() - start)
+
+        if (proof.length != 96 + cols.length * 32) {
+            throw Error(`${node.uri} responded with ${proof.length} bytes, expected ${96 + cols.length * 32}`)
+        }
+
+        return proof
+    }
+
+    // Fetches & verifies given cells, one multiproof per row, cells of same
+    // row are expected to be next to each other, as application's cells are
+    //
+    // Returns status of each cell, in order of `indices`
+    async fetchAndVerifyRows(blockNumber, totalCols, indices, commitment) {
+        const rows = []
+        for (const { row, col } of indices) {
+            if (rows.length == 0 || rows[rows.length - 1].row != row) {
+                rows.push({ row, cols: [] })
+            }
+            rows[rows.length - 1].cols.push(col)
+        }
+
+        const verified = []
+        for (const { row, cols } of rows) {
+            const proof = await this.policy.retry(_ => this.askMultiProof(blockNumber, row, cols))
+            verified.push(...verifyMultiproof(parseInt(blockNumber), parseInt(totalCols), row, cols, commitment, proof))
+        }
+
+        return verified
+    }
+
+    // Fetches header of same block, by hash, from all other full nodes &
+    // compares commitment bytes & data matrix dimensions with what primary
+    // full node served
+    //
+    // Returns list of disagreements, empty if all reachable nodes agree
+    //
+    // @note Nodes which are unreachable or yet to see this block are skipped,
+    // so are the ones lagging behind or following other branch
+    async crossCheckHeader(header) {
+        const disputes = []
+
+        for (const { uri, api } of this.nodes.slice(1)) {
+            let other
+            try {
+
+                other = await api.rpc.chain.getHeader(header.hash)
+                if (other.isEmpty) {
+                    continue
+                }
+
+            } catch (e) {
+
+                console.error(`${uri} : ${e.toString()}`)
+                continue
+
+            }
+
+            for (const field of ['commitment', 'rows', 'cols']) {
+                if (header.extrinsicsRoot[field].toHex() != other.extrinsicsRoot[field].toHex()) {
+                    disputes.push({ node: uri, field })
+                }
+            }
+        }
+
+        return disputes
+    }
+
+    // Marks block as disputed, if any of full nodes disagrees with primary
+    // about header, so that it doesn't get reported as confident, while
+    // earlier dispute is cleared, once all of them agree
+    //
+    // Returns true if block was found to be disputed
+    async checkDisputed(header) {
+        const disputes = await this.crossCheckHeader(header)
+        if (disputes.length == 0) {
+            this.state.clearDisputed(header.hash.toHex())
+            return false
+        }
+
+        for (const { node, field } of disputes) {
+            console.log(`⚠️  ${node} disagrees on ${field} of block ${BigInt(header.number)}`)
+        }
+
+        this.state.markDisputed(header.hash.toHex(), disputes)
+        return true
+    }
+
     // Given a block, which is already fetched, attempts to
     // verify block content by checking commitment & proof asked by
     // cell indices
-    verifyBlock(blockNumber, totalRows, totalCols, indices, commitment, proof) {
+    //
+    // Confidence of block, as merged by verifier, is kept in state
+    //
+    // Returns status of each cell, along with data chunk of verified ones
+    verifyBlock(blockNumber, blockHash, totalRows, totalCols, indices, commitment, proof) {
         try {
-            
-            const ret = verifyProof(parseInt(blockNumber), parseInt(totalRows), parseInt(totalCols), getRows(indices), getColumns(indices), commitment, proof)
-            if (ret > 0) {
-                this.state.setConfidence(BigInt(blockNumber).toString(), ret)
-                return true
-            }
 
-            throw Error('zero rounds passed')
+            const { cells, confidence } = verifyProofWithData(parseInt(blockNumber), blockHash, parseInt(totalRows), parseInt(totalCols), getRows(indices), getColumns(indices), commitment, proof)
+            this.state.setConfidence(blockHash, confidence)
+            return cells
+
         } catch (e) {
             console.log(`❌ Verification attempt failed for block ${BigInt(blockNumber)} : ${e.toString()}`)
+            return indices.map(({ row, col }) => ({ row, col, verified: false, data: null }))
+        }
+    }
+
+    // Given cells of block, serves already verified ones from proof cache,
+    // asks peers for rest & fetches whatever's left from full node, with
+    // retries, proofs from either source are verified
+    //
+    // Returns status of each cell, in order of `indices`
+    async fetchAndVerify(blockNumber, blockHash, totalRows, totalCols, indices, commitment) {
+        const cached = lookupProofs(blockHash, indices)
+        const missing = indices.filter((_, i) => !cached[i])
+
+        // other light clients are asked first, full node only for what they
+        // couldn't serve
+        const { cells: fromPeers, confidence } = missing.length > 0
+            ? fetchFromPeers(parseInt(blockNumber), blockHash, parseInt(totalRows), parseInt(totalCols), getRows(missing), getColumns(missing), commitment)
+            : { cells: [], confidence: null }
+        this.state.setConfidence(blockHash, confidence)
+        const unserved = missing.filter((_, i) => !fromPeers[i].verified)
+
+        let fetched = []
+        if (unserved.length > 0) {
+            const proof = await this.policy.retry(_ => this.askProof(blockNumber, unserved))
+            fetched = this.verifyBlock(blockNumber, blockHash, totalRows, totalCols, unserved, commitment, proof)
+        }
+
+        const resolved = fromPeers.map(cell => cell.verified ? cell : fetched.shift())
+        return indices.map(({ row, col }, i) => cached[i]
+            ? { row, col, verified: true, data: '0x' + Buffer.from(cached[i].slice(48)).toString('hex') }
+            : resolved.shift())
+    }
+
+    // Samples block in rounds, as per sampling policy, each round asks for
+    // proofs of fresh cells, till target confidence is reached
+    //
+    // Proof fetches are retried with backoff, if block still can't reach
+    // target confidence, because of failed cells, it's marked unavailable
+    //
+    // Returns true if target confidence was reached
+    async sampleBlock(header) {
+        setBacklogSize(++this.backlog)
+
+        try {
+            return await this.sampleBlockInRounds(header)
+        } finally {
+            setBacklogSize(--this.backlog)
+        }
+    }
+
+    async sampleBlockInRounds(header) {
+        const blockNumber = header.number
+        const hash = header.hash.toHex()
+        const number = BigInt(blockNumber).toString()
+        const totalRows = parseInt(header.extrinsicsRoot.rows)
+        const totalCols = parseInt(header.extrinsicsRoot.cols)
+        const commitment = [...header.extrinsicsRoot.commitment]
+
+        for (let round = 0; round < this.policy.maxRounds; round++) {
+            if (this.policy.reachedTarget(this.state.getConfidence(hash))) {
+                break
+            }
+
+            const indices = generateRandomDataMatrixIndices(totalRows, totalCols, this.state.getSampled(hash))
+            if (indices.length == 0) {
+                break
+            }
+
+            const sampled = indices.map(({ row, col }) => cellKey(row, col))
+
+            let cells
+            try {
+                cells = await this.fetchAndVerify(blockNumber, hash, totalRows, totalCols, indices, commitment)
+            } catch (e) {
+                console.log(`❌ Failed to fetch proofs for block ${number} : ${e.toString()}`)
+                this.state.recordSampling(hash, number, sampled, 0, indices.length)
+                continue
+            }
+
+            const verified = cells.filter(({ verified }) => verified).length
+
+            this.state.recordSampling(hash, number, sampled, verified, indices.length - verified)
+            this.state.recordCells(hash, cells)
+        }
+
+        const verified = this.state.getVerified(hash)
+        const failed = this.state.getFailed(hash)
+        const confidence = this.state.getConfidence(hash)
+
+        if (this.policy.isUnavailable(confidence, failed)) {
+            console.log(`🚨 Block ${number} is unavailable, ${failed} cell(s) failed, ${verified} verified`)
+            this.state.markUnavailable(hash, { verified, failed })
+            this.announceUnavailable(blockNumber, hash, commitment)
+        } else if (!(this.state.getUnavailableReason(hash) || {}).alerted) {
+            // cells confirmed failing, after alert from peers, keep block unavailable
+            this.state.clearUnavailable(hash)
+        }
+
+        if (!this.policy.reachedTarget(confidence)) {
             return false
         }
+
+        setLatestVerified(parseInt(blockNumber))
+        return true
+    }
+
+    // Gossips failed cells of block to other light clients, which
+    // re-sample them before believing block is unavailable
+    //
+    // @note At max 255 cells are announced, which is enough for
+    // others to check
+    announceUnavailable(blockNumber, hash, commitment) {
+        const cells = this.state.getFailedCells(hash).slice(0, 255)
+        if (cells.length == 0) {
+            return
+        }
+
+        if (announceUnavailable(parseInt(blockNumber), hash, commitment, cells) == 1) {
+            console.log(`📣 Announced unavailable block ${BigInt(blockNumber)} to peers`)
+        }
+    }
+
+    // Re-samples cells of each alert received from other light clients &
+    // marks block unavailable only if any of them fails to verify here too
+    async processAlerts() {
+        let alert
+        while ((alert = nextAlert()) !== null) {
+            try {
+                await this.checkAlert(alert)
+            } catch (e) {
+                console.log(`❌ Failed to check alert for block ${alert.number} : ${e.toString()}`)
+            }
+        }
+    }
+
+    async checkAlert({ number, hash, commitment, cells }) {
+        if (this.state.isUnavailable(hash)) {
+            return
+        }
+
+        // alert must be about block full node knows & commitment it carries,
+        // otherwise there's nothing to re-sample
+        const header = await this.policy.retry(_ => this.api.rpc.chain.getHeader(hash))
+        if (header.extrinsicsRoot.commitment.toHex() != commitment) {
+            console.log(`⚠️  Ignoring alert for block ${number}, commitment doesn't match`)
+            return
+        }
+
+        const totalRows = parseInt(header.extrinsicsRoot.rows)
+        const totalCols = parseInt(header.extrinsicsRoot.cols)
+        const indices = cells.filter(({ row, col }) => row < totalRows && col < totalCols)
+        if (indices.length == 0) {
+            return
+        }
+
+        let checked
+        try {
+            const proof = await this.policy.retry(_ => this.askProof(header.number, indices))
+            checked = this.verifyBlock(header.number, hash, totalRows, totalCols, indices, [...header.extrinsicsRoot.commitment], proof)
+        } catch (e) {
+            // full node not serving proofs is what alert is about
+            checked = indices.map(({ row, col }) => ({ row, col, verified: false, data: null }))
+        }
+
+        const verified = checked.filter(({ verified }) => verified).length
+        const failed = checked.length - verified
+
+        this.state.recordSampling(hash, BigInt(header.number).toString(), indices.map(({ row, col }) => cellKey(row, col)), verified, failed)
+        this.state.recordCells(hash, checked)
+
+        if (failed == 0) {
+            console.log(`👍 Rejected alert for block ${number}, all ${verified} cell(s) verified`)
+            return
+        }
+
+        console.log(`🚨 Confirmed alert for block ${number}, ${failed} cell(s) failed`)
+        this.state.markUnavailable(hash, { verified: this.state.getVerified(hash), failed: this.state.getFailed(hash), alerted: true })
     }
 
     // Given block number ( as string ), get block hash
@@ -67,72 +382,240 @@ class LightClient {
         }
     }
 
-    // Given block hash, attempts to fetch block
-    async fetchBlockByHash(hash) {
-        try {
-
-            const block = await this.api.rpc.chain.getBlock(hash)
-            return block
-
-        } catch (e) {
-
-            console.error(e.toString())
-            return null
+    // Given block number, returns hash of canonical block at that height,
+    // as per header chain, falling back to full node for blocks which are
+    // older than tracked window
+    async canonicalHash(num) {
+        return canonicalHash(parseInt(num)) || await this.fetchBlockHashByNumber(num)
+    }
 
-        }
+    // Proofs are asked for by block number, so only block which is canonical
+    // at its height can be sampled, otherwise proofs would be of some other
+    // block, on other branch
+    async isCanonical(header, hash) {
+        return await this.canonicalHash(header.number.toString()) == hash
     }
 
-    // Given block number, first fetch hash of block, 
-    // then fetch block using hash
-    async fetchBlockByNumber(num) {
-        const hash = await this.fetchBlockHashByNumber(num)
+    // Function for fetching canonical block at given height & attempting
+    // to verify it
+    async processBlockByNumber(num) {
+        const hash = await this.canonicalHash(num.toString())
         if (!hash) {
-            return null
+            return {
+                status: 0,
+                block: num
+            }
         }
 
-        return await this.fetchBlockByHash(hash)
+        return await this.processBlockByHash(hash)
     }
 
     // Function for fetching single block & attempting
     // to verify block by asking for `N` proof(s), in a batch call
-    processBlockByNumber(num) {
+    processBlockByHash(hash) {
         return new Promise(async (res, _) => {
             const start = new Date().getTime()
 
-            console.log(`🛠   Verifying block : ${num}, on request`)
+            console.log(`🛠   Verifying block : ${hash}, on request`)
+
+            let block = null
+            try {
+                block = await this.policy.retry(_ => this.api.rpc.chain.getBlock(hash))
+            } catch (e) {
+                console.error(e.toString())
+            }
 
-            const block = await this.fetchBlockByNumber(num.toString())
             if (!block) {
 
                 res({
                     status: 0,
-                    block: num
+                    block: hash
+                })
+                return
+
+            }
+
+            if (!await this.isCanonical(block.block.header, hash)) {
+
+                console.log(`❌ Block : ${hash} is not canonical, refusing to verify`)
+                res({
+                    status: 0,
+                    block: hash
                 })
                 return
 
             }
 
-            const blockNumber = block.block.header.number
-            const totalRows = block.block.header.extrinsicsRoot.rows
-            const totalCols = block.block.header.extrinsicsRoot.cols
-            const indices = generateRandomDataMatrixIndices(
-                parseInt(totalRows),
-                parseInt(totalCols))
-            const commitment = [...block.block.header.extrinsicsRoot.commitment]
-            const proof = await this.askProof(blockNumber, indices)
+            if (await this.checkDisputed(block.block.header)) {
+
+                res({
+                    status: 0,
+                    block: hash
+                })
+                return
+
+            }
 
-            const status = await this.verifyBlock(blockNumber, totalRows, totalCols, indices, commitment, proof)
+            const status = await this.sampleBlock(block.block.header)
             if (status) {
-                console.log(`✅ Verified block : ${num} in ${humanizeDuration(new Date().getTime() - start)}, on request`)
+                console.log(`✅ Verified block : ${block.block.header.number} ( ${hash} ) in ${humanizeDuration(new Date().getTime() - start)}, on request`)
             }
 
             res({
                 status: status ? 1 : 0,
-                block: num
+                block: hash
             })
         })
     }
 
+    // Fetches & verifies exactly those cells of block, which hold data of
+    // given application, as per application id index in header, then
+    // decodes application's extrinsics out of them
+    //
+    // @note Verifier reports count of verified cells as `u8`, so cells
+    // are verified in batches of 255, unless multiproofs are enabled, in
+    // which case one multiproof is fetched for each row
+    async fetchAppData(hash, appId) {
+        if (!config.app_data_lookup) {
+            return { error: 'Headers carry no application data index' }
+        }
+
+        let block
+        try {
+            block = await this.policy.retry(_ => this.api.rpc.chain.getBlock(hash))
+        } catch (e) {
+            return { error: e.toString() }
+        }
+
+        const header = block.block.header
+        if (!await this.isCanonical(header, hash)) {
+            return { error: 'Block is not canonical' }
+        }
+
+        const cells = appCells(header.toU8a(), appId)
+        if (!cells) {
+            return { error: 'Failed to decode header' }
+        }
+
+        if (cells.length == 0) {
+            return { extrinsics: [] }
+        }
+
+        const totalRows = parseInt(header.extrinsicsRoot.rows)
+        const totalCols = parseInt(header.extrinsicsRoot.cols)
+        const commitment = [...header.extrinsicsRoot.commitment]
+
+        const verified = []
+        const batches = []
+        if (config.multiproof) {
+            batches.push(_ => this.fetchAndVerifyRows(header.number, totalCols, cells, commitment))
+        } else {
+            for (let i = 0; i < cells.length; i += 255) {
+                const indices = cells.slice(i, i + 255)
+                batches.push(_ => this.fetchAndVerify(header.number, hash, totalRows, totalCols, indices, commitment))
+            }
+        }
+
+        for (const batch of batches) {
+            try {
+                verified.push(...await batch())
+            } catch (e) {
+                return { error: e.toString() }
+            }
+        }
+
+        this.state.recordCells(hash, verified)
+
+        const failed = verified.filter(({ verified }) => !verified)
+        if (failed.length > 0) {
+            return {
+                error: `${failed.length} cell(s) of application failed verification`,
+                failedCells: failed.map(({ row, col }) => ({ row, col }))
+            }
+        }
+
+        return decodeAppData(verified.map(({ data }) => data))
+    }
+
+    // Imports header into header chain, fetching missing ancestors from
+    // full node when header can't be linked right away
+    //
+    // In case of reorg, canonical index of header chain is switched to new
+    // branch, while confidence of retracted blocks is kept against their hash
+    //
+    // Returns true if header is linked & can be trusted for verification
+    async importHeader(header) {
+        const pending = [header]
+
+        while (pending.length > 0) {
+            const { status, ancestor } = importHeader(pending[pending.length - 1].toU8a())
+
+            switch (status) {
+                case 0:
+                case 2:
+                    pending.pop()
+                    break
+
+                case 1:
+                    console.log(`🔀 Reorg detected, common ancestor : ${ancestor}`)
+                    pending.pop()
+                    break
+
+                case -2:
+                    if (pending.length > MaxBackfill) {
+                        console.log(`❌ Failed to link header ${header.number} to header chain`)
+                        return false
+                    }
+
+                    try {
+                        pending.push(await this.api.rpc.chain.getHeader(pending[pending.length - 1].parentHash))
+                    } catch (e) {
+                        console.error(e.toString())
+                        return false
+                    }
+                    break
+
+                default:
+                    console.log(`❌ Rejected header ${header.number}, status : ${status}`)
+                    return false
+            }
+        }
+
+        return true
+    }
+
+    // Fetches current GRANDPA authority set from full node, which is
+    // trusted, same as first header of header chain, all later set
+    // changes are followed from header digests
+    async initFinality() {
+        try {
+
+            const authorities = await this.api.rpc.state.call('GrandpaApi_grandpa_authorities', '0x')
+            const setId = await this.api.query.grandpa.currentSetId()
+
+            return initAuthoritySet(parseInt(setId), authorities) == 1
+
+        } catch (e) {
+
+            console.error(e.toString())
+            return false
+
+        }
+    }
+
+    // Verifies GRANDPA justification & on success, verifier marks justified
+    // block & its ancestors, as finalized
+    importJustification(justification) {
+        const { status, number, hash } = verifyJustification(justification)
+        if (status != 0) {
+            console.log(`❌ Rejected justification, status : ${status}`)
+            return false
+        }
+
+        console.log(`🏁 Finalized block : ${number} ( ${hash} )`)
+        return true
+    }
+
     updateLatest(number) {
         this.state.updateLatest(number)
     }
@@ -0,0 +1,57 @@
+const config = require('./config')
+
+const sleep = ms => new Promise(res => setTimeout(res, ms))
+
+// Decides how hard light client tries, before giving up on a block
+//
+// Failed proof fetches are retried with exponential backoff, blocks which
+// are below target confidence get fresh cells sampled in next round &
+// blocks for which valid proofs can't be obtained, even after all rounds,
+// are escalated as unavailable
+class SamplingPolicy {
+
+    constructor() {
+        // Target confidence ( out of 100 ), block is sampled till this is reached
+        this.targetConfidence = config.target_confidence
+        // At max these many rounds of sampling are attempted, for reaching target
+        this.maxRounds = config.max_sampling_rounds
+        // Failed proof fetches are retried these many times, before giving up on round
+        this.maxRetries = config.max_retries
+        // Initial delay ( in ms ) between retries, doubled after each attempt
+        this.retryDelay = config.retry_delay_ms
+    }
+
+    // Invokes `fn` till it succeeds or retries are exhausted, in which
+    // case last error is thrown
+    async retry(fn) {
+        let delay = this.retryDelay
+
+        for (let attempt = 0; ; attempt++) {
+            try {
+                return await fn()
+            } catch (e) {
+                if (attempt >= this.maxRetries) {
+                    throw e
+                }
+
+                console.log(`🔁 Attempt ${attempt + 1} failed : ${e.toString()}, retrying in ${delay} ms`)
+                await sleep(delay)
+                delay *= 2
+            }
+        }
+    }
+
+    // Confidence ( out of 100 ) is as per verification report of block
+    reachedTarget(confidence) {
+        return confidence >= this.targetConfidence
+    }
+
+    // Block is unavailable when target couldn't be reached even after
+    // all rounds & some of sampled cells failed to be proven
+    isUnavailable(confidence, failed) {
+        return !this.reachedTarget(confidence) && failed > 0
+    }
+
+}
+
+module.exports = { SamplingPolicy }
@@ -1,12 +1,15 @@
 const express = require('express')
 const cors = require('cors')
 const { serialiseConfidence } = require('./utils')
+const { blockReport, blockReportCbor } = require('./verifier')
 const morgan = require('morgan')
 
-const port = process.env.PORT || 7000
+const config = require('./config')
+
+const [host, port] = config.api_address.split(/:(?=\d+$)/)
 let state, lc
 
-const lookupConfidence = async number => {
+const lookupConfidence = async block => {
 
     // This is a closure hook, which will be invoked before
     // responding to obtained confidence RPC query
@@ -18,46 +21,151 @@ const lookupConfidence = async number => {
     // attempt to gain confidence & then respond back to client
     //
     // @note It can be time consuming for second case
-    async function wrapperOnConfidenceFetcher(number) {
-        if (number < 1n) {
-            return 0
+    async function wrapperOnConfidenceFetcher(hash) {
+        // Full nodes disagreed on header of this block, so it's cross
+        // checked again & no confidence can be claimed for it, till they
+        // agree
+        if (state.isDisputed(hash)) {
+            await lc.processBlockByHash(hash)
+            return state.isDisputed(hash) ? 0 : state.getConfidence(hash)
         }
 
-        if (state.alreadyVerified(number.toString(10))) {
-            return state.getConfidence(number.toString(10))
+        // Blocks below target confidence get fresh cells sampled,
+        // unless they're already found to be unavailable
+        if (state.alreadyVerified(hash) && (lc.policy.reachedTarget(state.getConfidence(hash)) || state.isUnavailable(hash))) {
+            return state.getConfidence(hash)
         }
 
-        if (state.latestBlock < number) {
-            return 0
+        await lc.processBlockByHash(hash)
+        return state.getConfidence(hash)
+    }
+
+    async function respond(number, hash) {
+        const confidence = hash ? await wrapperOnConfidenceFetcher(hash) : 0
+        if (number === undefined && hash) {
+            number = state.getNumber(hash)
         }
 
-        const resp = await lc.processBlockByNumber(number)
-        if (resp.status != 1) {
-            return 0
+        const canonical = number !== undefined && hash
+            ? await lc.canonicalHash(number.toString()) == hash
+            : false
+
+        const resp = {
+            number: number === undefined ? null : parseInt(number),
+            hash,
+            canonical,
+            confidence,
+            serialisedConfidence: number === undefined ? null : serialiseConfidence(number, Math.round(confidence * 10 ** 7)),
+            finalized: canonical && state.isFinalized(number, hash)
         }
 
-        return state.getConfidence(number.toString(10))
+        if (hash && state.isUnavailable(hash)) {
+            resp.unavailable = true
+            resp.failedCells = state.getUnavailableReason(hash).failed
+            resp.alerted = !!state.getUnavailableReason(hash).alerted
+        }
+
+        if (hash && state.isDisputed(hash)) {
+            resp.disputed = true
+            resp.disputes = state.getDisputes(hash)
+        }
+
+        return resp
     }
 
-    async function getConfidence(number) {
-        const confidence = await wrapperOnConfidenceFetcher(BigInt(number))
+    // Queries by number are answered with confidence of
+    // canonical block at that height
+    async function getConfidenceByNumber(number) {
+        number = BigInt(number)
+        if (number < 1n || state.latestBlock < number) {
+            return respond(number.toString(10), null)
+        }
+
+        return respond(number.toString(10), await lc.canonicalHash(number.toString(10)))
+    }
+
+    // Queries by hash are answered with confidence of that
+    // very block, be it canonical or not
+    async function getConfidenceByHash(hash) {
+        return respond(undefined, hash.toLowerCase())
+    }
+
+    return typeof block === 'string' && /^0[xX][0-9a-fA-F]{64}$/.test(block)
+        ? getConfidenceByHash(block)
+        : typeof block === 'string' && /^((0[xX][0-9a-fA-F]+)|(\d+))$/.test(block)
+            ? getConfidenceByNumber(block)
+            : typeof block === 'number'
+                ? getConfidenceByNumber(block.toString())
+                :
+                {
+                    number: block,
+                    confidence: 0,
+                    error: 'Block must be number/ hash'
+                }
+}
+
+// Returns status of all sampled cells of block ( given by number or
+// hash ), along with data chunks of verified ones
+const lookupCells = async block => {
+    const hash = typeof block === 'string' && /^0[xX][0-9a-fA-F]{64}$/.test(block)
+        ? block.toLowerCase()
+        : /^((0[xX][0-9a-fA-F]+)|(\d+))$/.test(block)
+            ? await lc.canonicalHash(BigInt(block).toString(10))
+            : null
+
+    if (!hash) {
         return {
-            number: parseInt(number),
-            confidence,
-            serialisedConfidence: serialiseConfidence(number, Math.round(confidence * 10 ** 7))
+            block,
+            cells: [],
+            error: 'Block must be number/ hash'
+        }
+    }
+
+    return {
+        number: state.getNumber(hash) === undefined ? null : parseInt(state.getNumber(hash)),
+        hash,
+        cells: state.getCells(hash)
+    }
+}
+
+// Returns verification report of block ( given by number or hash ), which
+// is null if no cell of block is yet verified
+const lookupReport = async block => {
+    const hash = typeof block === 'string' && /^0[xX][0-9a-fA-F]{64}$/.test(block)
+        ? block.toLowerCase()
+        : /^((0[xX][0-9a-fA-F]+)|(\d+))$/.test(block)
+            ? await lc.canonicalHash(BigInt(block).toString(10))
+            : null
+
+    if (!hash) {
+        return { block, report: null, error: 'Block must be number/ hash' }
+    }
+
+    return { hash, report: blockReport(hash) }
+}
+
+// Returns verified & decoded extrinsics of given application,
+// for block given by number or hash
+const lookupAppData = async (block, appId) => {
+    const hash = typeof block === 'string' && /^0[xX][0-9a-fA-F]{64}$/.test(block)
+        ? block.toLowerCase()
+        : /^((0[xX][0-9a-fA-F]+)|(\d+))$/.test(block)
+            ? await lc.canonicalHash(BigInt(block).toString(10))
+            : null
+
+    if (!hash || !/^\d+$/.test(appId)) {
+        return {
+            block,
+            appId,
+            error: 'Block must be number/ hash & application id must be number'
         }
     }
 
-    return typeof number === 'string' && /^((0[xX][0-9a-fA-F]+)|(\d+))$/.test(number)
-        ? getConfidence(number)
-        : typeof number === 'number'
-            ? getConfidence(number.toString())
-            :
-            {
-                number,
-                confidence: 0,
-                error: 'Block number must be number/ string'
-            }
+    return {
+        hash,
+        appId: parseInt(appId),
+        ...await lc.fetchAppData(hash, parseInt(appId))
+    }
 }
 
 const startServer = (_state, _lc) => {
@@ -73,8 +181,33 @@ const startServer = (_state, _lc) => {
         res.status(200).json(await lookupConfidence(req.params.block))
     })
 
-    app.listen(port, _ => {
-        console.log(`✅ Running server @ http://localhost:${port}`)
+    app.get('/v1/cells/:block', async (req, res) => {
+        res.status(200).json(await lookupCells(req.params.block))
+    })
+
+    // Report is served as JSON, unless CBOR is asked for, using
+    // `Accept: application/cbor`, in which case it's sent as kept in storage
+    app.get('/v1/report/:block', async (req, res) => {
+        const { hash, report, error } = await lookupReport(req.params.block)
+        if (error) {
+            return res.status(400).json({ block: req.params.block, error })
+        }
+        if (!report) {
+            return res.status(404).json({ hash, error: 'No cell of block is verified yet' })
+        }
+
+        res.format({
+            'application/json': _ => res.status(200).json(report),
+            'application/cbor': _ => res.status(200).type('application/cbor').send(blockReportCbor(hash)),
+        })
+    })
+
+    app.get('/v1/appdata/:block/:appId', async (req, res) => {
+        res.status(200).json(await lookupAppData(req.params.block, req.params.appId))
+    })
+
+    app.listen(parseInt(port), host.replace(/^\[|\]$/g, ''), _ => {
+        console.log(`✅ Running server @ http://${config.api_address}`)
     })
 }
 
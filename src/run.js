@@ -1,7 +1,9 @@
 const { setUp } = require('./setup')
-const { generateRandomDataMatrixIndices } = require('./utils')
 const humanizeDuration = require('humanize-duration')
 
+// Alerts received from other light clients are picked up this often ( in ms )
+const AlertPollInterval = 1000
+
 // Subscribing to chain tip & attempt to run
 // block verification and confidence gaining life cycle
 // for each block seen/ mined in chain, after light client
@@ -10,9 +12,40 @@ const startLightClient = async _ => {
 
     const [lc, api] = await setUp()
 
+    // Following finality, so that confidence can be reported
+    // as final, only for finalized blocks
+    if (await lc.initFinality()) {
+        api.rpc.grandpa.subscribeJustifications(justification => lc.importJustification(justification))
+    } else {
+        console.log(`⚠️  Failed to fetch GRANDPA authority set, finality won't be tracked`)
+    }
+
+    // Alerts from other light clients are re-sampled in background,
+    // one batch at a time
+    let checkingAlerts = false
+    setInterval(async _ => {
+        if (checkingAlerts) {
+            return
+        }
+
+        checkingAlerts = true
+        try {
+            await lc.processAlerts()
+        } finally {
+            checkingAlerts = false
+        }
+    }, AlertPollInterval)
+
     api.rpc.chain.subscribeNewHeads(async header => {
 
         console.log(`🚀  Chain tip @ ${header.number}`)
+
+        // Header must link to already known chain, before
+        // trusting commitment carried by it
+        if (!await lc.importHeader(header)) {
+            return
+        }
+
         // keeping track of latest block of chain
         lc.updateLatest(BigInt(header.number))
 
@@ -24,16 +57,11 @@ const startLightClient = async _ => {
         const start = new Date().getTime()
         console.log(`🛠   Verifying block : ${header.number}`)
 
-        const blockNumber = header.number
-        const totalRows = header.extrinsicsRoot.rows
-        const totalCols = header.extrinsicsRoot.cols
-        const indices = generateRandomDataMatrixIndices(
-            parseInt(totalRows),
-            parseInt(totalCols))
-        const commitment = [...header.extrinsicsRoot.commitment]
-        const proof = await lc.askProof(blockNumber, indices)
+        if (await lc.checkDisputed(header)) {
+            return
+        }
 
-        if (await lc.verifyBlock(blockNumber, totalRows, totalCols, indices, commitment, proof)) {
+        if (await lc.sampleBlock(header)) {
             console.log(`✅ Verified block : ${header.number} in ${humanizeDuration(new Date().getTime() - start)}`)
         }
 
@@ -2,15 +2,16 @@ const { ApiPromise, WsProvider } = require('@polkadot/api')
 const { BlockConfidence } = require('./state')
 const { startServer } = require('./rpc')
 const { LightClient } = require('./light')
+const { startMetricsServer, startP2P } = require('./verifier')
 
-const WSURI = process.env.WSURI || 'ws://localhost:9944'
+const config = require('./config')
 
-// Initialised Polkadot API, which is to be used
-// for interacting with node RPC API
-const setUp = async _ => {
+// Given one full node endpoint, initialises Polkadot API,
+// which is to be used for interacting with node RPC API
+const createApi = async uri => {
 
-    const provider = new WsProvider(WSURI)
-    let api = await ApiPromise.create({
+    const provider = new WsProvider(uri)
+    return await ApiPromise.create({
         provider,
         types: {
             ExtrinsicsRoot: {
@@ -19,12 +20,22 @@ const setUp = async _ => {
                 rows: 'u16',
                 cols: 'u16'
             },
+            DataLookupIndexItem: {
+                appId: 'Compact<u32>',
+                start: 'Compact<u32>'
+            },
+            DataLookup: {
+                size: 'Compact<u32>',
+                index: 'Vec<DataLookupIndexItem>'
+            },
             Header: {
                 parentHash: 'Hash',
                 number: 'Compact<BlockNumber>',
                 stateRoot: 'Hash',
                 extrinsicsRoot: 'ExtrinsicsRoot',
-                digest: 'Digest'
+                digest: 'Digest',
+                // chains predating application data don't carry its index
+                ...(config.app_data_lookup ? { appDataLookup: 'DataLookup' } : {})
             },
             Cell: {
                 row: 'u32',
@@ -46,16 +57,70 @@ const setUp = async _ => {
                         }
                     ],
                     type: 'Vec<u8>'
+                },
+                queryMultiProof: {
+                    description: 'Ask for single Kate multiproof of several cells of one row, given block number, row & columns',
+                    params: [
+                        {
+                            name: 'blockNumber',
+                            type: 'u64'
+                        },
+                        {
+                            name: 'row',
+                            type: 'u32'
+                        },
+                        {
+                            name: 'cols',
+                            type: 'Vec<u32>'
+                        }
+                    ],
+                    type: 'Vec<u8>'
+                }
+            },
+            grandpa: {
+                subscribeJustifications: {
+                    description: 'Subscribes to GRANDPA justifications, as blocks get finalized',
+                    params: [],
+                    pubsub: [
+                        'justifications',
+                        'subscribeJustifications',
+                        'unsubscribeJustifications'
+                    ],
+                    type: 'Bytes'
                 }
             }
         }
     })
 
+}
+
+// Connects to all configured full nodes & sets up light client
+//
+// First endpoint is treated as primary & used for following chain tip,
+// rest of them are used for cross checking headers & serving proof requests
+//
+// @note Returned API handle is of primary full node
+const setUp = async _ => {
+
+    const nodes = []
+    for (const uri of config.endpoints) {
+        nodes.push({ uri, api: await createApi(uri) })
+        console.log(`🔌 Connected to full node @ ${uri}`)
+    }
+
     const state = new BlockConfidence()
-    const lc = new LightClient(api, state)
+    const lc = new LightClient(nodes, state)
     startServer(state, lc)
 
-    return [lc, api]
+    if (startMetricsServer(config.metrics_port) == 1) {
+        console.log(`✅ Serving metrics @ http://localhost:${config.metrics_port}/metrics`)
+    }
+
+    // @note Failing to join peer to peer network isn't fatal, proofs
+    // are then fetched only from full nodes
+    startP2P()
+
+    return [lc, nodes[0].api]
 
 }
 
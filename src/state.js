@@ -1,24 +1,151 @@
+const { blockReport, isFinalized } = require('./verifier')
+
+// Confidence is kept per block hash, so that blocks on different forks,
+// sharing same block number, are tracked separately
 class BlockConfidence {
 
     constructor() {
         this.blocks = {}
+        this.disputed = {}
+        this.unavailable = {}
+        this.cells = {}
+        this.confidence = {}
         this.latestBlock = 0n
     }
 
-    alreadyVerified(number) {
-        return number in this.blocks
+    alreadyVerified(hash) {
+        return hash in this.blocks
+    }
+
+    incrementConfidence(hash, number) {
+        this.blocks[hash] = {
+            number,
+            rounds: ((this.blocks[hash] || {}).rounds || 0) + 1
+        }
+    }
+
+    // Keeps confidence of block's merged report, as returned by verifier,
+    // null when no cell could be checked, which leaves it as is
+    setConfidence(hash, confidence) {
+        if (confidence !== null) {
+            this.confidence[hash] = confidence
+        }
+    }
+
+    // Accumulates outcome of one sampling round, where `sampled` is list
+    // of cell keys, for which proofs were asked
+    recordSampling(hash, number, sampled, verified, failed) {
+        const block = this.blocks[hash] || { number, rounds: 0, sampled: [], failed: 0 }
+
+        this.blocks[hash] = {
+            number,
+            rounds: block.rounds + verified,
+            sampled: [...(block.sampled || []), ...sampled],
+            failed: (block.failed || 0) + failed
+        }
+    }
+
+    // Keeps status of each sampled cell, along with data chunk of
+    // verified ones, so that it can be served without refetching
+    recordCells(hash, cells) {
+        const known = this.cells[hash] || {}
+
+        for (const cell of cells) {
+            known[`${cell.row}:${cell.col}`] = cell
+        }
+
+        this.cells[hash] = known
+    }
+
+    getCells(hash) {
+        return Object.values(this.cells[hash] || {})
+    }
+
+    // Cells already sampled for block, keyed as `row:col`
+    getSampled(hash) {
+        return new Set((this.blocks[hash] || {}).sampled || [])
+    }
+
+    // Sampled cells, which couldn't be verified, as `{ row, col }`
+    getFailedCells(hash) {
+        const known = this.cells[hash] || {}
+
+        return [...this.getSampled(hash)]
+            .filter(key => !(known[key] || {}).verified)
+            .map(key => {
+                const [row, col] = key.split(':').map(v => parseInt(v))
+                return { row, col }
+            })
     }
 
-    incrementConfidence(number) {
-        this.blocks[number] = (this.blocks[number] || 0) + 1
+    getVerified(hash) {
+        return (this.blocks[hash] || {}).rounds || 0
     }
 
-    setConfidence(number, confidence) {
-        this.blocks[number] = confidence
+    getFailed(hash) {
+        return (this.blocks[hash] || {}).failed || 0
     }
 
-    getConfidence(number) {
-        return (1 - (1 / Math.pow(2, this.blocks[number] || 0))) * 100
+    // Valid proofs couldn't be obtained for block, even after
+    // retrying & re-sampling
+    markUnavailable(hash, reason) {
+        this.unavailable[hash] = reason
+    }
+
+    clearUnavailable(hash) {
+        delete this.unavailable[hash]
+    }
+
+    isUnavailable(hash) {
+        return hash in this.unavailable
+    }
+
+    getUnavailableReason(hash) {
+        return this.unavailable[hash]
+    }
+
+    // Block number of already verified block
+    getNumber(hash) {
+        return (this.blocks[hash] || {}).number
+    }
+
+    // Keeps track of which full nodes disagreed with primary
+    // one & on which header fields
+    markDisputed(hash, disputes) {
+        this.disputed[hash] = disputes
+    }
+
+    clearDisputed(hash) {
+        delete this.disputed[hash]
+    }
+
+    isDisputed(hash) {
+        return hash in this.disputed
+    }
+
+    getDisputes(hash) {
+        return this.disputed[hash] || []
+    }
+
+    // Only moves forward, as GRANDPA finality is never reverted
+    // Only justified block & its ancestors are finalized, which is told by
+    // verifier, by hash, so that blocks of other branches, at same heights,
+    // aren't
+    isFinalized(number, hash) {
+        return isFinalized(parseInt(number), hash)
+    }
+
+    // Confidence ( out of 100 ), as computed by verifier, over distinct
+    // cells verified for block, out of whole extended matrix
+    //
+    // Blocks not verified in this process, are looked up from verifier
+    getConfidence(hash) {
+        if (hash in this.confidence) {
+            return this.confidence[hash]
+        }
+
+        const report = blockReport(hash)
+        return report ? report.confidence : 0
     }
 
     updateLatest(num) {
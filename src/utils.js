@@ -1,4 +1,5 @@
-const AskProofCount = parseInt(process.env.AskProofCount) || 10
+const { sample_count: AskProofCount } = require('./config')
+const { sampleCells } = require('./verifier')
 
 const getRows = indices => indices.map(({ row, _ }) => row)
 
@@ -7,47 +8,22 @@ const getColumns = indices => indices.map(({ _, col }) => col)
 // Generates unique random data matrix indices, to be used when querying
 // full node for proofs, for a certain block number
 //
-// `rows` & `cols` specifies data matrix size, as per that
-// indices are generated
-const generateRandomDataMatrixIndices = (rows, cols) => {
-    const target = Math.min(rows * cols, AskProofCount)
-    const indices = {}
-
-    while (Object.keys(indices).length < target) {
-        const row = getRandomInt(0, rows)
-        const col = getRandomInt(0, cols)
-
-        const key1 = `${row}${col}`
-        if (!(key1 in indices)) {
-            indices[key1] = { row, col }
-
-            if (Object.keys(indices).length == target) {
-                break
-            }
-        }
-
-        if (row == col) {
-            continue
-        }
-
-        if(!(row < cols && col < rows)) {
-            continue
-        }
-
-        const key2 = `${col}${row}`
-        if (!(key2 in indices)) {
-            indices[key2] = { row: col, col: row }
-        }
-    }
-
-    return Object.entries(indices).map(v => v[1])
+// `rows` & `cols` specifies original data matrix size, indices are generated
+// out of whole extended matrix i.e. twice as many rows, by verifier, same as
+// confidence is computed over
+//
+// Cells in `exclude` ( keyed as `row:col` ) are never picked, so that
+// fresh cells can be sampled for already sampled block
+const generateRandomDataMatrixIndices = (rows, cols, exclude = new Set()) => {
+    const sampled = [...exclude].map(key => {
+        const [row, col] = key.split(':').map(v => parseInt(v))
+        return { row, col }
+    })
+
+    return sampleCells(rows, cols, sampled, AskProofCount)
 }
 
-// Return random integer in specified range
-// where lower bound is inclusive, but other end is not
-const getRandomInt = (low, high) => {
-    return Math.floor(Math.random() * (high - low)) + low
-}
+const cellKey = (row, col) => `${row}:${col}`
 
 // Given block number & respective confidence ( represented out of 10 ^ 9 )
 // encodes block number in upper 28 bytes & confidence in lower 4 bytes
@@ -56,5 +32,5 @@ const getRandomInt = (low, high) => {
 const serialiseConfidence = (block, confidence) => (BigInt(block) << BigInt(32) | BigInt(confidence)).toString(10)
 
 module.exports = {
-    generateRandomDataMatrixIndices, getRows, getColumns, serialiseConfidence
+    generateRandomDataMatrixIndices, getRows, getColumns, serialiseConfidence, cellKey
 }
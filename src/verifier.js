@@ -1,25 +1,66 @@
+const crypto = require('crypto')
 const ffi = require('ffi-napi')
 const ref = require('ref-napi')
 const array = require('ref-array-di')(ref)
 
 const U8Array = array(ref.types.uint8)
+const U16Array = array(ref.types.uint16)
 
 // Creating interface to be used for calling verifier
 const lib = ffi.Library('libverifier', {
     verify_proof: ['uint8', ['uint64', 'size_t', 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t']],
+    verify_proof_with_scheme: ['uint8', ['uint8', 'uint64', 'size_t', 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t']],
+    verify_proof_with_data: ['uint8', ['uint64', U8Array, 'size_t', 'size_t', 'size_t', U16Array, 'size_t', U16Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', ref.refType(ref.types.double)]],
+    import_header: ['int8', [U8Array, 'size_t', ref.refType(ref.types.uint64)]],
+    canonical_hash: ['uint8', ['uint64', U8Array, 'size_t']],
+    init_authority_set: ['uint8', ['uint64', U8Array, 'size_t']],
+    verify_justification: ['int8', [U8Array, 'size_t', ref.refType(ref.types.uint64), U8Array, 'size_t']],
+    is_finalized: ['int8', ['uint64', U8Array, 'size_t']],
+    start_metrics_server: ['uint8', ['uint16']],
+    observe_proof_fetch: ['void', ['double']],
+    set_backlog_size: ['void', ['uint64']],
+    set_latest_verified: ['void', ['uint64']],
+    verify_multiproof: ['int8', ['uint64', 'size_t', 'uint16', U16Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t']],
+    verify_row: ['int8', [U8Array, 'size_t', U8Array, 'size_t', 'size_t']],
+    reconstruct_matrix: ['int8', ['size_t', 'size_t', U16Array, 'size_t', U16Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t']],
+    start_p2p: ['uint8', []],
+    fetch_from_peers: ['uint8', ['uint64', U8Array, 'size_t', 'size_t', 'size_t', U16Array, 'size_t', U16Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', ref.refType(ref.types.double)]],
+    announce_unavailable: ['uint8', ['uint64', U8Array, 'size_t', U8Array, 'size_t', U16Array, U16Array, 'size_t']],
+    next_alert: ['pointer', []],
+    build_fraud_proof: ['int64', [U8Array, 'size_t', 'size_t', 'size_t', 'size_t', U16Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t', U8Array, 'size_t']],
+    verify_fraud_proof: ['int8', [U8Array, 'size_t', 'size_t', 'size_t', U8Array, 'size_t']],
+    sample_cells: ['int64', ['size_t', 'size_t', 'uint64', U16Array, U16Array, 'size_t', U16Array, U16Array, 'size_t']],
+    lookup_proofs: ['size_t', [U8Array, 'size_t', U16Array, U16Array, 'size_t', U8Array, U8Array]],
+    app_cells: ['int64', [U8Array, 'size_t', 'uint32', U16Array, U16Array, 'size_t']],
+    decode_app_data: ['pointer', [U8Array, 'size_t']],
+    block_report: ['pointer', [U8Array, 'size_t']],
+    block_report_cbor: ['int64', [U8Array, 'size_t', U8Array, 'size_t']],
+    load_config: ['pointer', ['string']],
+    init_verifier: ['pointer', []],
+    free_string: ['void', ['pointer']],
 })
 
 module.exports = {
 
+    // Commitment schemes, cells can be verified against
+    Scheme: {
+        Row: 0,
+        Column: 1,
+    },
+
     // Returns how many proof verification attempts were successful as `u8`
-    verifyProof: (block, totalRows, totalCols, rows, cols, commitment, proof) => {
+    //
+    // With `Scheme.Column`, `commitment` is expected to be made of column
+    // commitments & `proof` to be opening of each cell's column
+    verifyProof: (block, totalRows, totalCols, rows, cols, commitment, proof, scheme = 0) => {
 
         const _rows = U8Array(rows)
         const _cols = new U8Array(cols)
         const _commitment = new U8Array(commitment)
         const _proof = new U8Array(proof)
 
-        return lib.verify_proof(
+        return lib.verify_proof_with_scheme(
+            scheme,
             block, 
             totalRows, totalCols,
             _rows, _rows.length,
@@ -27,6 +68,463 @@ module.exports = {
             _commitment, _commitment.length,
             _proof, _proof.length)
 
+    },
+
+    // Verifies proofs & returns status of each cell, in order of `rows`/ `cols`,
+    // along with its 32 bytes data chunk ( hex encoded ), if verified, as
+    // `{ cells, confidence }`
+    //
+    // Proofs of verified cells get cached against block hash, confidence is of
+    // block's report, merged with these cells, null if none could be checked
+    verifyProofWithData: (block, hash, totalRows, totalCols, rows, cols, commitment, proof) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        const _rows = new U16Array(rows)
+        const _cols = new U16Array(cols)
+        const _commitment = new U8Array(commitment)
+        const _proof = new U8Array(proof)
+        const _out = new U8Array(cols.length * 33)
+        const _confidence = ref.alloc(ref.types.double, -1)
+
+        lib.verify_proof_with_data(
+            block,
+            _hash, _hash.length,
+            totalRows, totalCols,
+            _rows, _rows.length,
+            _cols, _cols.length,
+            _commitment, _commitment.length,
+            _proof, _proof.length,
+            _out, _out.length,
+            _confidence)
+
+        const out = _out.buffer
+        const cells = cols.map((col, i) => {
+            const verified = out[i * 33] == 1
+            return {
+                row: rows[i],
+                col,
+                verified,
+                data: verified ? '0x' + out.slice(i * 33 + 1, i * 33 + 33).toString('hex') : null
+            }
+        })
+
+        return { cells, confidence: _confidence.deref() < 0 ? null : _confidence.deref() }
+
+    },
+
+    // Asks other light clients for proofs of given cells & verifies them,
+    // returned in same shape as of `verifyProofWithData`
+    //
+    // Cells which peers couldn't serve are returned as not verified
+    fetchFromPeers: (block, hash, totalRows, totalCols, rows, cols, commitment) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        const _rows = new U16Array(rows)
+        const _cols = new U16Array(cols)
+        const _commitment = new U8Array(commitment)
+        const _out = new U8Array(cols.length * 33)
+        const _confidence = ref.alloc(ref.types.double, -1)
+
+        lib.fetch_from_peers(
+            block,
+            _hash, _hash.length,
+            totalRows, totalCols,
+            _rows, _rows.length,
+            _cols, _cols.length,
+            _commitment, _commitment.length,
+            _out, _out.length,
+            _confidence)
+
+        const out = _out.buffer
+        const cells = cols.map((col, i) => {
+            const verified = out[i * 33] == 1
+            return {
+                row: rows[i],
+                col,
+                verified,
+                data: verified ? '0x' + out.slice(i * 33 + 1, i * 33 + 33).toString('hex') : null
+            }
+        })
+
+        return { cells, confidence: _confidence.deref() < 0 ? null : _confidence.deref() }
+
+    },
+
+    // Verifies whole row, given its 48 bytes commitment & 32 bytes evaluation
+    // of each cell of row, concatenated in column order
+    //
+    // Returns 1 if row matches commitment, 0 if not & -1 on malformed input
+    verifyRow: (commitment, evaluations, totalCols) => {
+
+        const _commitment = new U8Array(commitment)
+        const _evaluations = new U8Array(evaluations)
+
+        return lib.verify_row(
+            _commitment, _commitment.length,
+            _evaluations, _evaluations.length,
+            totalCols)
+
+    },
+
+    // Picks `count` fresh cells of block to be sampled, uniformly out of whole
+    // extended matrix, as confidence assumes, leaving out `sampled` ones ( as
+    // `{ row, col }` )
+    //
+    // Returns picked cells as `{ row, col }`, fewer if not that many are left
+    sampleCells: (totalRows, totalCols, sampled, count) => {
+
+        const _sampledRows = new U16Array(sampled.map(({ row }) => row))
+        const _sampledCols = new U16Array(sampled.map(({ col }) => col))
+        const _rows = new U16Array(count)
+        const _cols = new U16Array(count)
+
+        const picked = lib.sample_cells(
+            totalRows, totalCols,
+            crypto.randomBytes(6).readUIntLE(0, 6),
+            _sampledRows, _sampledCols, sampled.length,
+            _rows, _cols, count)
+
+        if (picked < 0) {
+            throw Error(`Invalid matrix dimensions ${totalRows} x ${totalCols}`)
+        }
+
+        return [...Array(picked).keys()].map(i => ({ row: _rows[i], col: _cols[i] }))
+
+    },
+
+    // Looks up proofs of already verified cells of block, in cache
+    //
+    // Returns 80 bytes proof for each of cached cells & null for rest
+    lookupProofs: (hash, indices) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        const _rows = new U16Array(indices.map(({ row }) => row))
+        const _cols = new U16Array(indices.map(({ col }) => col))
+        const _out = new U8Array(indices.length * 80)
+        const _found = new U8Array(indices.length)
+
+        lib.lookup_proofs(_hash, _hash.length, _rows, _cols, indices.length, _out, _found)
+
+        const out = _out.buffer
+        return indices.map((_, i) => _found[i] == 1 ? [...out.slice(i * 80, i * 80 + 80)] : null)
+
+    },
+
+    // Verifies multiproof of cells of one row, at columns `cols`, as served by
+    // `kate_queryMultiProof`
+    //
+    // Returns status of each cell, in order of `cols`, along with its 32 bytes
+    // data chunk ( hex encoded ), all cells verify or none does
+    verifyMultiproof: (block, totalCols, row, cols, commitment, proof) => {
+
+        const _cols = new U16Array(cols)
+        const _commitment = new U8Array(commitment)
+        const _proof = new U8Array(proof)
+
+        const verified = lib.verify_multiproof(
+            block,
+            totalCols, row,
+            _cols, _cols.length,
+            _commitment, _commitment.length,
+            _proof, _proof.length) == 1
+
+        return cols.map((col, i) => ({
+            row,
+            col,
+            verified,
+            data: verified ? '0x' + Buffer.from(proof.slice(96 + i * 32, 96 + (i + 1) * 32)).toString('hex') : null
+        }))
+
+    },
+
+    // Builds bad encoding proof for column `col`, given its cells as
+    // `{ row, proof }`, with 80 bytes opening of each
+    //
+    // Returns `{ status, proof }`, where proof is hex encoded SCALE bytes,
+    // see `build_fraud_proof` for negative status codes
+    buildFraudProof: (hash, totalRows, totalCols, col, cells, commitment) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        const _rows = new U16Array(cells.map(({ row }) => row))
+        const _proof = new U8Array([].concat(...cells.map(({ proof }) => [...proof])))
+        const _commitment = new U8Array(commitment)
+
+        const args = [
+            _hash, _hash.length,
+            totalRows, totalCols, col,
+            _rows, _rows.length,
+            _proof, _proof.length,
+            _commitment, _commitment.length]
+
+        const len = lib.build_fraud_proof(...args, new U8Array(0), 0)
+        if (len < 0) {
+            return { status: len, proof: null }
+        }
+
+        const _out = new U8Array(len)
+        lib.build_fraud_proof(...args, _out, _out.length)
+
+        return { status: 1, proof: '0x' + _out.buffer.toString('hex') }
+
+    },
+
+    // Returns 1 if hex encoded bad encoding proof proves column is incorrectly
+    // encoded, as per dimensions & commitment in header of block proof is
+    // about, see `verify_fraud_proof` for rest
+    verifyFraudProof: (proof, totalRows, totalCols, commitment) => {
+
+        const _proof = new U8Array([...Buffer.from(proof.slice(2), 'hex')])
+        const _commitment = new U8Array(commitment)
+
+        return lib.verify_fraud_proof(_proof, _proof.length, totalRows, totalCols, _commitment, _commitment.length)
+
+    },
+
+    // Given verified cells ( as returned by `verifyProofWithData` ), rebuilds
+    // whole extended data matrix of `totalRows * 2` rows, which is returned
+    // as array of rows, each being array of hex encoded cell data
+    //
+    // Status is 1 when all reconstructed rows match commitment, see
+    // `reconstruct_matrix` for rest of status codes
+    reconstructMatrix: (totalRows, totalCols, cells, commitment) => {
+
+        const known = cells.filter(({ verified }) => verified)
+
+        const _rows = new U16Array(known.map(({ row }) => row))
+        const _cols = new U16Array(known.map(({ col }) => col))
+        const _data = new U8Array([].concat(...known.map(({ data }) => [...Buffer.from(data.slice(2), 'hex')])))
+        const _commitment = new U8Array(commitment)
+        const _out = new U8Array(totalRows * 2 * totalCols * 32)
+
+        const status = lib.reconstruct_matrix(
+            totalRows, totalCols,
+            _rows, _rows.length,
+            _cols, _cols.length,
+            _data, _data.length,
+            _commitment, _commitment.length,
+            _out, _out.length)
+
+        if (status < 0) {
+            return { status, matrix: null }
+        }
+
+        const out = _out.buffer
+        const matrix = [...Array(totalRows * 2).keys()].map(row =>
+            [...Array(totalCols).keys()].map(col => {
+                const start = (row * totalCols + col) * 32
+                return '0x' + out.slice(start, start + 32).toString('hex')
+            }))
+
+        return { status, matrix }
+
+    },
+
+    // Given SCALE encoded header, returns cells of data matrix holding
+    // data of application, null if header couldn't be decoded
+    appCells: (encoded, appId) => {
+
+        const _header = new U8Array([...encoded])
+        const count = lib.app_cells(_header, _header.length, appId, new U16Array(0), new U16Array(0), 0)
+        if (count < 0) {
+            return null
+        }
+
+        const _rows = new U16Array(count)
+        const _cols = new U16Array(count)
+        lib.app_cells(_header, _header.length, appId, _rows, _cols, count)
+
+        return [...Array(count).keys()].map(i => ({ row: _rows[i], col: _cols[i] }))
+
+    },
+
+    // Decodes application's extrinsics, given hex encoded data
+    // chunks of all cells of application, in order
+    //
+    // Returns either `{ extrinsics }` or `{ error }`
+    decodeAppData: chunks => {
+
+        const _data = new U8Array([].concat(...chunks.map(chunk => [...Buffer.from(chunk.slice(2), 'hex')])))
+
+        const ptr = lib.decode_app_data(_data, _data.length)
+        const resp = ref.readCString(ptr, 0)
+        lib.free_string(ptr)
+
+        return JSON.parse(resp)
+
+    },
+
+    // Imports SCALE encoded header into header chain maintained by verifier
+    //
+    // Returns status code ( see `import_header` ) & common ancestor's
+    // block number, which is only meaningful when reorg happened
+    importHeader: encoded => {
+
+        const _header = new U8Array([...encoded])
+        const ancestor = ref.alloc(ref.types.uint64, 0)
+
+        const status = lib.import_header(_header, _header.length, ancestor)
+        return { status, ancestor: BigInt(ancestor.deref()) }
+
+    },
+
+    // Returns hex encoded hash of canonical block at given height, as per
+    // header chain, null if block number is outside of tracked window
+    canonicalHash: block => {
+
+        const _hash = new U8Array(32)
+        if (lib.canonical_hash(block, _hash, _hash.length) != 1) {
+            return null
+        }
+
+        return '0x' + _hash.buffer.toString('hex')
+
+    },
+
+    // Sets trusted GRANDPA authority set, given set id & SCALE encoded
+    // authority list, returns 1 on success
+    initAuthoritySet: (setId, authorities) => {
+
+        const _authorities = new U8Array([...authorities])
+        return lib.init_authority_set(setId, _authorities, _authorities.length)
+
+    },
+
+    // Verifies SCALE encoded GRANDPA justification
+    //
+    // Returns status code ( see `verify_justification` ) & finalized
+    // block number & hash, which are only meaningful when status is 0
+    verifyJustification: justification => {
+
+        const _justification = new U8Array([...justification])
+        const number = ref.alloc(ref.types.uint64, 0)
+        const _hash = new U8Array(32)
+
+        const status = lib.verify_justification(_justification, _justification.length, number, _hash, _hash.length)
+        return { status, number: BigInt(number.deref()), hash: '0x' + _hash.buffer.toString('hex') }
+
+    },
+
+    // Whether block is finalized i.e. it's latest finalized block or one of its
+    // ancestors, blocks outside of tracked header chain are never
+    isFinalized: (number, hash) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        return lib.is_finalized(number, _hash, _hash.length) == 1
+
+    },
+
+    // Starts serving prometheus metrics at `/metrics` on given port,
+    // returns 1 on success
+    startMetricsServer: port => lib.start_metrics_server(port),
+
+    // Joins peer to peer network, if p2p port is configured
+    //
+    // Returns 1 on success, 0 on failure & 2 if not configured
+    startP2P: _ => lib.start_p2p(),
+
+    // Warns other light clients about block, for which given cells
+    // couldn't be verified against commitment
+    announceUnavailable: (block, hash, commitment, cells) => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+        const _commitment = new U8Array(commitment)
+        const _rows = new U16Array(cells.map(({ row }) => row))
+        const _cols = new U16Array(cells.map(({ col }) => col))
+
+        return lib.announce_unavailable(block, _hash, _hash.length, _commitment, _commitment.length, _rows, _cols, cells.length)
+
+    },
+
+    // Oldest alert received from other light clients, as
+    // `{ number, hash, commitment, cells }`, null if there's none
+    nextAlert: _ => {
+
+        const ptr = lib.next_alert()
+        if (ref.isNull(ptr)) {
+            return null
+        }
+
+        const resp = ref.readCString(ptr, 0)
+        lib.free_string(ptr)
+
+        return JSON.parse(resp)
+
+    },
+
+    // Verification report of block, merged over all sampling rounds, as
+    // `{ number, hash, totalRows, totalCols, cells, micros, confidence, scheme, srs }`,
+    // where each cell is `{ row, col, outcome, micros }`
+    //
+    // Returns null if no cell of block is yet verified
+    blockReport: hash => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+
+        const ptr = lib.block_report(_hash, _hash.length)
+        const resp = JSON.parse(ref.readCString(ptr, 0))
+        lib.free_string(ptr)
+
+        if (resp.error) {
+            throw Error(resp.error)
+        }
+
+        return resp.report
+
+    },
+
+    // Same report, CBOR encoded, as it's kept in storage, null if no cell
+    // of block is yet verified
+    blockReportCbor: hash => {
+
+        const _hash = new U8Array([...Buffer.from(hash.slice(2), 'hex')])
+
+        const len = lib.block_report_cbor(_hash, _hash.length, new U8Array(0), 0)
+        if (len < 0) {
+            throw Error(`failed to read report of ${hash}`)
+        }
+        if (len == 0) {
+            return null
+        }
+
+        const _out = new U8Array(len)
+        lib.block_report_cbor(_hash, _hash.length, _out, _out.length)
+
+        return Buffer.from(_out.buffer)
+
+    },
+
+    // Records time taken ( in ms ) for fetching one batch of proofs
+    observeProofFetch: ms => lib.observe_proof_fetch(ms / 1000),
+
+    setBacklogSize: size => lib.set_backlog_size(size),
+
+    setLatestVerified: block => lib.set_latest_verified(block),
+
+    // Resolves & validates configuration, given command line arguments
+    //
+    // Returns either `{ config }` or `{ error }`
+    loadConfig: args => {
+
+        const ptr = lib.load_config(JSON.stringify(args))
+        const resp = ref.readCString(ptr, 0)
+        lib.free_string(ptr)
+
+        return JSON.parse(resp)
+
+    },
+
+    // Creates storage directory & loads public parameters, as per
+    // configuration loaded before
+    //
+    // Returns `{ error }` on failure
+    initVerifier: () => {
+
+        const ptr = lib.init_verifier()
+        const resp = ref.readCString(ptr, 0)
+        lib.free_string(ptr)
+
+        return JSON.parse(resp)
+
     }
 
 }
@@ -0,0 +1,139 @@
+// Verification throughput, for tracking regressions
+//
+// Run as `cargo bench --features test-support`, criterion keeps results of
+// last run under `target/criterion`, so each run is compared against it
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use verifier::testing::{load_public_params, public_params, verify_opening, Fixture, SrsFormat};
+
+// (original rows, columns) of benchmarked matrices
+const MATRICES: [(usize, usize); 3] = [(4, 32), (16, 64), (64, 256)];
+
+// cells sampled per block, 15 being light client's default
+const SAMPLES: [usize; 4] = [1, 8, 15, 64];
+
+// Picks `count` distinct cells, spread over extended matrix
+fn sample(fixture: &Fixture, count: usize) -> Vec<(usize, usize)> {
+    let total = fixture.total_rows * 2 * fixture.total_cols;
+    let step = (total / count).max(1);
+
+    (0..count)
+        .map(|i| (i * step) % total)
+        .map(|i| (i / fixture.total_cols, i % fixture.total_cols))
+        .collect()
+}
+
+// Verifies given cells through C ABI, same way light client does
+fn verify(fixture: &Fixture, rows: &[u8], cols: &[u8], proof: &[u8]) -> u8 {
+    verifier::verify_proof(
+        1,
+        fixture.total_rows,
+        fixture.total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        fixture.commitment.as_ptr(),
+        fixture.commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    )
+}
+
+// Proof & row commitment check of one cell, without thread pool
+fn single_cell(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_cell");
+
+    for &(rows, cols) in MATRICES.iter() {
+        let fixture = Fixture::random(rows, cols, 1);
+        let (row, col) = (rows, cols / 2);
+        let proof = fixture.proof(row, col);
+        let commitment = fixture.row_commitment(row);
+
+        group.bench_with_input(BenchmarkId::from_parameter(format!("{}x{}", rows * 2, cols)), &proof, |b, proof| {
+            b.iter(|| assert!(verify_opening(commitment, black_box(proof), col, cols).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+// Sampled cells of one block, as verified by light client, for each
+// matrix size & sample count
+fn per_block(c: &mut Criterion) {
+    let mut group = c.benchmark_group("per_block");
+    group.sample_size(20);
+
+    for &(rows, cols) in MATRICES.iter() {
+        let fixture = Fixture::random(rows, cols, 2);
+
+        for &count in SAMPLES.iter() {
+            let cells = sample(&fixture, count);
+            let sampled_rows: Vec<u8> = cells.iter().map(|&(row, _)| row as u8).collect();
+            let sampled_cols: Vec<u8> = cells.iter().map(|&(_, col)| col as u8).collect();
+            let proof = fixture.proofs(&cells);
+
+            group.throughput(Throughput::Elements(count as u64));
+            group.bench_with_input(
+                BenchmarkId::new(format!("{}x{}", rows * 2, cols), count),
+                &proof,
+                |b, proof| {
+                    b.iter(|| {
+                        assert_eq!(verify(&fixture, &sampled_rows, &sampled_cols, black_box(proof)) as usize, count)
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+// Deserialising embedded public parameters & trimming them down to
+// row width, both of which are done before any cell gets verified
+fn params(c: &mut Criterion) {
+    let mut group = c.benchmark_group("params");
+    group.sample_size(10);
+
+    group.bench_function("load", |b| b.iter(|| load_public_params(None, SrsFormat::Dusk).unwrap()));
+
+    for &(_, cols) in MATRICES.iter() {
+        group.bench_with_input(BenchmarkId::new("trim", cols), &cols, |b, &cols| {
+            b.iter(|| public_params().trim(black_box(cols)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+// Same cells verified in one call, as done for whole block, versus one
+// call per cell, showing how much parallel verification saves
+fn batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+    group.sample_size(10);
+
+    let (rows, cols) = MATRICES[1];
+    let fixture = Fixture::random(rows, cols, 3);
+    let cells = sample(&fixture, u8::MAX as usize);
+    let sampled_rows: Vec<u8> = cells.iter().map(|&(row, _)| row as u8).collect();
+    let sampled_cols: Vec<u8> = cells.iter().map(|&(_, col)| col as u8).collect();
+    let proof = fixture.proofs(&cells);
+
+    group.throughput(Throughput::Elements(cells.len() as u64));
+    group.bench_function("one_call", |b| {
+        b.iter(|| assert_eq!(verify(&fixture, &sampled_rows, &sampled_cols, &proof) as usize, cells.len()))
+    });
+    group.bench_function("call_per_cell", |b| {
+        b.iter(|| {
+            for (pos, chunk) in proof.chunks(80).enumerate() {
+                assert_eq!(verify(&fixture, &sampled_rows[pos..pos + 1], &sampled_cols[pos..pos + 1], chunk), 1);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, single_cell, per_block, params, batch);
+criterion_main!(benches);
@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+// data chunks of application's cells, as is
+fuzz_target!(|data: &[u8]| {
+    verifier::free_string(verifier::decode_app_data(data.as_ptr(), data.len()));
+});
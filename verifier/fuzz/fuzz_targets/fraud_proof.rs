@@ -0,0 +1,47 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// total_rows u16 | total_cols u16 | col u16 | out_len u16 | hash | rows |
+// openings | commitment | proof ...
+//
+// Builder is fed with first fields, while proof being verified is rest of input
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let total_rows = input.u16() as usize;
+    let total_cols = input.u16() as usize;
+    let col = input.u16() as usize;
+    let mut out = vec![0u8; input.u16() as usize];
+    let hash = input.bytes();
+    let rows = input.u16s();
+    let openings = input.bytes();
+    let commitment = input.bytes();
+    let proof = input.rest();
+
+    verifier::build_fraud_proof(
+        hash.as_ptr(),
+        hash.len(),
+        total_rows,
+        total_cols,
+        col,
+        rows.as_ptr(),
+        rows.len(),
+        openings.as_ptr(),
+        openings.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        out.as_mut_ptr(),
+        out.len(),
+    );
+
+    verifier::verify_fraud_proof(
+        proof.as_ptr(),
+        proof.len(),
+        total_rows,
+        total_cols,
+        commitment.as_ptr(),
+        commitment.len(),
+    );
+});
@@ -0,0 +1,22 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// app_id u32 | cap u16 | header ...
+//
+// Headers are imported into same header chain across runs, so that reorgs
+// get exercised too
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let app_id = input.u32();
+    let cap = input.u16() as usize;
+    let header = input.rest();
+
+    let mut ancestor = 0u64;
+    verifier::import_header(header.as_ptr(), header.len(), &mut ancestor);
+
+    let (mut rows, mut cols) = (vec![0u16; cap], vec![0u16; cap]);
+    verifier::app_cells(header.as_ptr(), header.len(), app_id, rows.as_mut_ptr(), cols.as_mut_ptr(), cap);
+});
@@ -0,0 +1,27 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// set_id u64 | authorities | header | justification ...
+//
+// Header is imported first, so that justification can target known block
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let set_id = input.u64();
+    let authorities = input.bytes();
+    let header = input.bytes();
+    let justification = input.rest();
+
+    verifier::init_authority_set(set_id, authorities.as_ptr(), authorities.len());
+
+    let mut ancestor = 0u64;
+    verifier::import_header(header.as_ptr(), header.len(), &mut ancestor);
+
+    let mut number = 0u64;
+    let mut hash = [0u8; 32];
+    if verifier::verify_justification(justification.as_ptr(), justification.len(), &mut number, hash.as_mut_ptr(), hash.len()) == 0 {
+        verifier::is_finalized(number, hash.as_ptr(), hash.len());
+    }
+});
@@ -0,0 +1,34 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// total_rows u8 | total_cols u8 | rows | cols | commitment | data ...
+//
+// Dimensions are kept to a byte, as output buffer is sized after them
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let total_rows = input.u8() as usize;
+    let total_cols = input.u8() as usize;
+    let rows = input.u16s();
+    let cols = input.u16s();
+    let commitment = input.bytes();
+    let data = input.rest();
+    let mut out = vec![0u8; total_rows * 2 * total_cols * 32];
+
+    verifier::reconstruct_matrix(
+        total_rows,
+        total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        data.as_ptr(),
+        data.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        out.as_mut_ptr(),
+        out.len(),
+    );
+});
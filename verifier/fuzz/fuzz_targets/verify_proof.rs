@@ -0,0 +1,30 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// total_rows u16 | total_cols u16 | rows | cols | commitment | proof ...
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let total_rows = input.u16() as usize;
+    let total_cols = input.u16() as usize;
+    let rows = input.bytes();
+    let cols = input.bytes();
+    let commitment = input.bytes();
+    let proof = input.rest();
+
+    verifier::verify_proof(
+        1,
+        total_rows,
+        total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    );
+});
@@ -0,0 +1,38 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// out_len u16 | total_rows u16 | total_cols u16 | hash | rows u16s | cols u16s | commitment | proof ...
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let mut out = vec![0u8; input.u16() as usize];
+    let total_rows = input.u16() as usize;
+    let total_cols = input.u16() as usize;
+    let hash = input.bytes();
+    let rows = input.u16s();
+    let cols = input.u16s();
+    let commitment = input.bytes();
+    let proof = input.rest();
+    let mut confidence = -1f64;
+
+    verifier::verify_proof_with_data(
+        1,
+        hash.as_ptr(),
+        hash.len(),
+        total_rows,
+        total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+        out.as_mut_ptr(),
+        out.len(),
+        &mut confidence,
+    );
+});
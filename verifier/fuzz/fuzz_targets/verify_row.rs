@@ -0,0 +1,15 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use verifier_fuzz::Reader;
+
+// total_cols u16 | commitment | evaluations ...
+fuzz_target!(|input: &[u8]| {
+    let mut input = Reader::new(input);
+
+    let total_cols = input.u16() as usize;
+    let commitment = input.bytes();
+    let evals = input.rest();
+
+    verifier::verify_row(commitment.as_ptr(), commitment.len(), evals.as_ptr(), evals.len(), total_cols);
+});
@@ -0,0 +1,253 @@
+// Writes seed corpus of each fuzz target into `corpus/<target>`, built from
+// valid fixtures, so that fuzzers start from inputs which get past parsing
+//
+// Run from `fuzz` directory, as `cargo run --bin seed-corpus`
+
+use std::fs;
+use std::path::Path;
+
+use blake2_rfc::blake2b::blake2b;
+use codec::{Compact, Encode};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+use verifier::testing::Fixture;
+use verifier_fuzz::Writer;
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+fn write(target: &str, name: &str, input: Vec<u8>) {
+    let dir = Path::new("corpus").join(target);
+    fs::create_dir_all(&dir).expect("corpus directory created");
+    fs::write(dir.join(name), input).expect("seed written");
+}
+
+// Cells of whole extended column
+fn column(col: usize) -> Vec<(usize, usize)> {
+    (0..ROWS * 2).map(|row| (row, col)).collect()
+}
+
+fn verify_proof(fixture: &Fixture) {
+    let cells = [(0, 0), (3, 5), (7, 7)];
+    let rows: Vec<u8> = cells.iter().map(|&(row, _)| row as u8).collect();
+    let cols: Vec<u8> = cells.iter().map(|&(_, col)| col as u8).collect();
+    let proof = fixture.proofs(&cells);
+
+    write(
+        "verify_proof",
+        "valid",
+        Writer::default()
+            .u16(ROWS as u16)
+            .u16(COLS as u16)
+            .bytes(&rows)
+            .bytes(&cols)
+            .bytes(&fixture.commitment)
+            .rest(&proof),
+    );
+
+    write(
+        "verify_proof_with_data",
+        "valid",
+        Writer::default()
+            .u16((cells.len() * 33) as u16)
+            .u16(ROWS as u16)
+            .u16(COLS as u16)
+            .bytes(&[1u8; 32])
+            .u16s(&rows.iter().map(|&row| row as u16).collect::<Vec<_>>())
+            .u16s(&cols.iter().map(|&col| col as u16).collect::<Vec<_>>())
+            .bytes(&fixture.commitment)
+            .rest(&proof),
+    );
+}
+
+fn verify_row(fixture: &Fixture) {
+    for row in [0, ROWS * 2 - 1].iter() {
+        let evals: Vec<u8> = (0..COLS).flat_map(|col| fixture.cell_data(*row, col).to_vec()).collect();
+
+        write(
+            "verify_row",
+            &format!("row-{}", row),
+            Writer::default()
+                .u16(COLS as u16)
+                .bytes(fixture.row_commitment(*row))
+                .rest(&evals),
+        );
+    }
+}
+
+fn reconstruct_matrix(fixture: &Fixture) {
+    // parity rows only, just enough for reconstruction
+    let cells: Vec<(usize, usize)> = (0..ROWS).flat_map(|row| (0..COLS).map(move |col| (row * 2 + 1, col))).collect();
+    let rows: Vec<u16> = cells.iter().map(|&(row, _)| row as u16).collect();
+    let cols: Vec<u16> = cells.iter().map(|&(_, col)| col as u16).collect();
+    let data: Vec<u8> = cells.iter().flat_map(|&(row, col)| fixture.cell_data(row, col).to_vec()).collect();
+
+    write(
+        "reconstruct_matrix",
+        "parity",
+        Writer::default()
+            .u8(ROWS as u8)
+            .u8(COLS as u8)
+            .u16s(&rows)
+            .u16s(&cols)
+            .bytes(&fixture.commitment)
+            .rest(&data),
+    );
+}
+
+// Same incorrectly encoded block, as of tests, one parity cell of column 2
+// is replaced, while its row is still committed to
+fn fraud_proof() {
+    let good = Fixture::random(ROWS, COLS, 9);
+    let mut matrix = good.matrix.clone();
+    matrix[1][2] += verifier::testing::random_scalars(0, 1)[0];
+    let fixture = Fixture::from_extended(verifier::testing::public_params(), ROWS, COLS, matrix).unwrap();
+
+    let hash = [1u8; 32];
+    let cells = column(2);
+    let rows: Vec<u16> = cells.iter().map(|&(row, _)| row as u16).collect();
+    let openings = fixture.proofs(&cells);
+
+    let args = |out: &mut Vec<u8>| {
+        verifier::build_fraud_proof(
+            hash.as_ptr(),
+            hash.len(),
+            ROWS,
+            COLS,
+            2,
+            rows.as_ptr(),
+            rows.len(),
+            openings.as_ptr(),
+            openings.len(),
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    let len = args(&mut vec![]);
+    assert!(len > 0, "fraud proof of incorrectly encoded column built");
+    let mut proof = vec![0u8; len as usize];
+    args(&mut proof);
+
+    write(
+        "fraud_proof",
+        "bad-encoding",
+        Writer::default()
+            .u16(ROWS as u16)
+            .u16(COLS as u16)
+            .u16(2)
+            .u16(len as u16)
+            .bytes(&hash)
+            .u16s(&rows)
+            .bytes(&openings)
+            .bytes(&fixture.commitment)
+            .rest(&proof),
+    );
+}
+
+// SCALE encoded header, same as served by full node, with one application
+// occupying first few cells
+fn header(fixture: &Fixture, parent_hash: [u8; 32], number: u32) -> Vec<u8> {
+    let mut encoded = parent_hash.encode();
+    Compact(number).encode_to(&mut encoded);
+    [0u8; 32].encode_to(&mut encoded);
+    hash_of(&fixture.commitment).encode_to(&mut encoded);
+    fixture.commitment.encode_to(&mut encoded);
+    ((ROWS * 2) as u16).encode_to(&mut encoded);
+    (COLS as u16).encode_to(&mut encoded);
+    // no digest logs
+    Compact(0u32).encode_to(&mut encoded);
+    // application 1 occupies cells 0..5
+    Compact(5u32).encode_to(&mut encoded);
+    vec![(Compact(1u32), Compact(0u32))].encode_to(&mut encoded);
+
+    encoded
+}
+
+fn hash_of(encoded: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(blake2b(32, &[], encoded).as_bytes());
+    hash
+}
+
+fn headers(fixture: &Fixture) {
+    let genesis = header(fixture, [0u8; 32], 0);
+    let child = header(fixture, hash_of(&genesis), 1);
+
+    write("header", "genesis", Writer::default().u32(1).u16(8).rest(&genesis));
+    write("header", "child", Writer::default().u32(1).u16(8).rest(&child));
+}
+
+// Justification of block, signed by single authority, holding whole weight
+fn justification(fixture: &Fixture) {
+    let secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    let keypair = Keypair { secret, public };
+
+    let set_id = 0u64;
+    let round = 1u64;
+    let header = header(fixture, [0u8; 32], 1);
+    let target = (hash_of(&header), 1u32);
+
+    let mut payload = vec![1u8];
+    payload.extend(target.encode());
+    payload.extend((round, set_id).encode());
+    let signature = keypair.sign(&payload).to_bytes();
+
+    let mut justification = round.encode();
+    target.encode_to(&mut justification);
+    // single precommit, for commit target itself
+    Compact(1u32).encode_to(&mut justification);
+    target.encode_to(&mut justification);
+    signature.encode_to(&mut justification);
+    public.to_bytes().encode_to(&mut justification);
+    // no ancestry
+    Compact(0u32).encode_to(&mut justification);
+
+    let authorities = vec![(public.to_bytes(), 1u64)].encode();
+
+    write(
+        "justification",
+        "valid",
+        Writer::default()
+            .u64(set_id)
+            .bytes(&authorities)
+            .bytes(&header)
+            .rest(&justification),
+    );
+}
+
+// Application data, as SCALE encoded extrinsics, padded & split into chunks
+fn app_data() {
+    let mut data = vec![vec![1u8, 2, 3], vec![0u8; 40]].encode();
+    data.push(0x80);
+    while data.len() % 31 != 0 {
+        data.push(0);
+    }
+
+    let chunks: Vec<u8> = data
+        .chunks(31)
+        .flat_map(|chunk| {
+            let mut chunk = chunk.to_vec();
+            chunk.push(0);
+            chunk
+        })
+        .collect();
+
+    write("app_data", "valid", chunks);
+}
+
+fn main() {
+    let fixture = Fixture::random(ROWS, COLS, 1);
+
+    verify_proof(&fixture);
+    verify_row(&fixture);
+    reconstruct_matrix(&fixture);
+    fraud_proof();
+    headers(&fixture);
+    justification(&fixture);
+    app_data();
+
+    println!("✅  Seed corpus written to corpus/");
+}
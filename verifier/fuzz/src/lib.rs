@@ -0,0 +1,107 @@
+// Layout of fuzz inputs, shared by fuzz targets & seed corpus generator
+//
+// Each input is a sequence of fields, integers are little endian & byte
+// strings are prefixed with their length as `u16`. Fuzzer is free to cut
+// input anywhere, so missing integers read as zero & byte strings get
+// clamped to whatever is left, last field usually being rest of input
+
+pub struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(input: &'a [u8]) -> Reader<'a> {
+        Reader { input }
+    }
+
+    fn take(&mut self, len: usize) -> &'a [u8] {
+        let (taken, rest) = self.input.split_at(len.min(self.input.len()));
+        self.input = rest;
+        taken
+    }
+
+    fn int<const N: usize>(&mut self) -> [u8; N] {
+        let mut buf = [0u8; N];
+        let taken = self.take(N);
+        buf[..taken.len()].copy_from_slice(taken);
+        buf
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        self.int::<1>()[0]
+    }
+
+    pub fn u16(&mut self) -> u16 {
+        u16::from_le_bytes(self.int())
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.int())
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.int())
+    }
+
+    pub fn bytes(&mut self) -> &'a [u8] {
+        let len = self.u16() as usize;
+        self.take(len)
+    }
+
+    // Byte string read as list of `u16`s, two bytes each
+    pub fn u16s(&mut self) -> Vec<u16> {
+        self.bytes()
+            .chunks(2)
+            .map(|c| u16::from_le_bytes([c[0], *c.get(1).unwrap_or(&0)]))
+            .collect()
+    }
+
+    pub fn rest(&mut self) -> &'a [u8] {
+        self.take(self.input.len())
+    }
+}
+
+#[derive(Default)]
+pub struct Writer {
+    output: Vec<u8>,
+}
+
+impl Writer {
+    pub fn u8(mut self, v: u8) -> Self {
+        self.output.push(v);
+        self
+    }
+
+    pub fn u16(mut self, v: u16) -> Self {
+        self.output.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn u32(mut self, v: u32) -> Self {
+        self.output.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn u64(mut self, v: u64) -> Self {
+        self.output.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn bytes(mut self, v: &[u8]) -> Self {
+        assert!(v.len() <= u16::MAX as usize, "field too long for fuzz input");
+
+        self.output.extend_from_slice(&(v.len() as u16).to_le_bytes());
+        self.output.extend_from_slice(v);
+        self
+    }
+
+    pub fn u16s(self, v: &[u16]) -> Self {
+        let bytes: Vec<u8> = v.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect();
+        self.bytes(&bytes)
+    }
+
+    pub fn rest(mut self, v: &[u8]) -> Vec<u8> {
+        self.output.extend_from_slice(v);
+        self.output
+    }
+}
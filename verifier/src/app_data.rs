@@ -0,0 +1,90 @@
+use std::ops::Range;
+
+use codec::{Compact, Decode};
+
+// Each cell carries these many bytes of application data, last
+// byte of 32 bytes scalar is always kept zero
+pub const DATA_CHUNK_SIZE: usize = 31;
+
+// Application id index, as carried in header, telling from which
+// cell onwards data of each application starts
+#[derive(Clone, Debug, Default, Decode)]
+pub struct DataLookup {
+    // total number of cells occupied by application data
+    pub size: Compact<u32>,
+    // sorted by start cell
+    pub index: Vec<DataLookupItem>,
+}
+
+#[derive(Clone, Debug, Decode)]
+pub struct DataLookupItem {
+    pub app_id: Compact<u32>,
+    pub start: Compact<u32>,
+}
+
+impl DataLookup {
+    // Cells occupied by given application, in terms of position in original
+    // data matrix flattened in row major order, i.e. cell `i` is at
+    // `(i / total_cols, i % total_cols)` of original matrix
+    pub fn range_of(&self, app_id: u32) -> Option<Range<u32>> {
+        let pos = self.index.iter().position(|item| item.app_id.0 == app_id)?;
+
+        let start = self.index[pos].start.0;
+        let end = self
+            .index
+            .get(pos + 1)
+            .map(|item| item.start.0)
+            .unwrap_or(self.size.0);
+
+        Some(start..end)
+    }
+
+    // Row & column of each cell holding data of given application, in extended
+    // matrix, where original row `r` is extended row `2 * r`, lazily, as index
+    // in header may claim far more cells than matrix has, those beyond original
+    // matrix of given dimensions are left out
+    pub fn cells_of(
+        &self,
+        app_id: u32,
+        total_rows: usize,
+        total_cols: usize,
+    ) -> impl ExactSizeIterator<Item = (usize, usize)> {
+        let total = (total_rows * total_cols).min(u32::MAX as usize) as u32;
+        let range = self
+            .range_of(app_id)
+            .map(|range| range.start.min(total)..range.end.min(total))
+            .unwrap_or(0..0);
+
+        range.map(move |i| (2 * (i as usize / total_cols), i as usize % total_cols))
+    }
+}
+
+// Given 32 bytes data chunks of all cells of an application, in order,
+// recovers application's extrinsics
+//
+// Application data is SCALE encoded list of opaque extrinsics, padded as
+// per ISO/IEC 9797-1 method 2 i.e. `0x80` followed by zeros, upto multiple
+// of chunk size & split into 31 bytes chunks
+pub fn decode(chunks: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    if chunks.len() % 32 != 0 {
+        return Err(format!("data of length {} not multiple of 32", chunks.len()));
+    }
+
+    let mut data: Vec<u8> = chunks
+        .chunks(32)
+        .flat_map(|chunk| chunk[..DATA_CHUNK_SIZE].iter().cloned())
+        .collect();
+
+    match data.iter().rposition(|&b| b != 0) {
+        Some(pos) if data[pos] == 0x80 => data.truncate(pos),
+        _ => return Err("application data is not padded".to_string()),
+    }
+
+    let mut input = data.as_slice();
+    let extrinsics = <Vec<Vec<u8>>>::decode(&mut input).map_err(|e| format!("failed to decode application data : {}", e))?;
+    if !input.is_empty() {
+        return Err(format!("{} trailing bytes after application data", input.len()));
+    }
+
+    Ok(extrinsics)
+}
@@ -0,0 +1,263 @@
+// Checks openings using blst, without going through dusk-plonk, with only
+// G1 generator, G2 generator & [τ]₂ taken from public parameters
+//
+// Opening `(W, y)` of commitment `C` at `z` holds iff
+// e(C - y·g, h) == e(W, [τ]₂ - z·h)
+
+use ::blst::*;
+use dusk_plonk::commitment_scheme::kzg10::PublicParameters;
+
+use super::CellVerifier;
+use crate::row;
+
+// r - 1, where r is order of scalar field, as little endian limbs
+const MODULUS_MINUS_ONE: [u64; 4] = [
+    0xffff_ffff_0000_0000,
+    0x53bd_a402_fffe_5bfe,
+    0x3339_d808_09a1_d805,
+    0x73ed_a753_299d_7d48,
+];
+
+// Multiplicative generator of scalar field, roots of unity are derived from,
+// same as dusk-plonk's
+const GENERATOR: u64 = 7;
+
+pub struct BlstVerifier {
+    g: blst_p1_affine,
+    h: blst_p2_affine,
+    beta_h: blst_p2_affine,
+    // domains larger than this can't be opened with these parameters
+    max_degree: usize,
+}
+
+impl BlstVerifier {
+    // Compressed G1 generator, G2 generator & [τ]₂, along with maximum
+    // degree public parameters support
+    pub fn new(g: &[u8], h: &[u8], beta_h: &[u8], max_degree: usize) -> Result<BlstVerifier, String> {
+        Ok(BlstVerifier {
+            g: parse_p1(g, "G1 generator")?,
+            h: parse_p2(h, "G2 generator")?,
+            beta_h: parse_p2(beta_h, "[τ]₂")?,
+            max_degree,
+        })
+    }
+
+    // Takes opening key out of dusk-plonk serialised public parameters
+    pub fn from_params(public_params: &PublicParameters) -> Result<BlstVerifier, String> {
+        let (_, opening_key) = public_params.trim(1).map_err(|e| format!("{:?}", e))?;
+
+        BlstVerifier::new(
+            &opening_key.g.to_compressed(),
+            &opening_key.h.to_compressed(),
+            &opening_key.beta_h.to_compressed(),
+            public_params.max_degree(),
+        )
+    }
+}
+
+impl CellVerifier for BlstVerifier {
+    fn name(&self) -> &'static str {
+        "blst"
+    }
+
+    fn verify_opening(&self, commitment: &[u8], opening: &[u8], point: usize, domain_size: usize) -> Result<bool, String> {
+        if opening.len() != 80 {
+            return Err(format!("opening of length {}, expected 80", opening.len()));
+        }
+        row::check_cols(domain_size)?;
+
+        // same as dusk-plonk, domain is rounded up to power of two
+        let size = domain_size.next_power_of_two();
+        if point >= size {
+            return Err(format!("point {} out of domain of {} elements", point, domain_size));
+        }
+        if domain_size > self.max_degree {
+            return Err(format!("domain of {} elements exceeds maximum degree {}", domain_size, self.max_degree));
+        }
+
+        let commitment = parse_p1(commitment, "commitment")?;
+        let witness = parse_p1(&opening[..48], "witness")?;
+        let eval = parse_scalar(&opening[48..])?;
+        let z = domain_element(size, point);
+
+        // C - y·g
+        let lhs = add_p1(&commitment, &neg_p1(&mul_p1(&self.g, &eval)));
+        // [τ]₂ - z·h
+        let rhs = add_p2(&self.beta_h, &neg_p2(&mul_p2(&self.h, &z)));
+
+        Ok(pairings_equal(&lhs, &self.h, &witness, &rhs))
+    }
+}
+
+// Compressed G1 point, which must be in prime order subgroup
+fn parse_p1(bytes: &[u8], name: &str) -> Result<blst_p1_affine, String> {
+    if bytes.len() != 48 {
+        return Err(format!("{} of length {}, expected 48", name, bytes.len()));
+    }
+
+    let mut point = blst_p1_affine::default();
+    let valid = unsafe {
+        blst_p1_uncompress(&mut point, bytes.as_ptr()) == BLST_ERROR::BLST_SUCCESS && blst_p1_affine_in_g1(&point)
+    };
+
+    if valid {
+        Ok(point)
+    } else {
+        Err(format!("{} is not a valid point", name))
+    }
+}
+
+// Compressed G2 point, which must be in prime order subgroup
+fn parse_p2(bytes: &[u8], name: &str) -> Result<blst_p2_affine, String> {
+    if bytes.len() != 96 {
+        return Err(format!("{} of length {}, expected 96", name, bytes.len()));
+    }
+
+    let mut point = blst_p2_affine::default();
+    let valid = unsafe {
+        blst_p2_uncompress(&mut point, bytes.as_ptr()) == BLST_ERROR::BLST_SUCCESS && blst_p2_affine_in_g2(&point)
+    };
+
+    if valid {
+        Ok(point)
+    } else {
+        Err(format!("{} is not a valid point", name))
+    }
+}
+
+// Canonically encoded little endian scalar, as served by full node
+fn parse_scalar(bytes: &[u8]) -> Result<blst_scalar, String> {
+    let mut scalar = blst_scalar::default();
+    let valid = unsafe {
+        blst_scalar_from_lendian(&mut scalar, bytes.as_ptr());
+        blst_scalar_fr_check(&scalar)
+    };
+
+    if valid {
+        Ok(scalar)
+    } else {
+        Err("evaluation 0 is not a valid scalar".to_string())
+    }
+}
+
+fn fr_from_u64(v: u64) -> blst_fr {
+    let mut out = blst_fr::default();
+    unsafe { blst_fr_from_uint64(&mut out, [v, 0, 0, 0].as_ptr()) };
+    out
+}
+
+// Square & multiply, over little endian limbs of exponent
+fn pow(base: &blst_fr, exp: &[u64; 4]) -> blst_fr {
+    let mut acc = fr_from_u64(1);
+
+    for limb in exp.iter().rev() {
+        for bit in (0..64).rev() {
+            let prev = acc;
+            unsafe { blst_fr_sqr(&mut acc, &prev) };
+
+            if (limb >> bit) & 1 == 1 {
+                let prev = acc;
+                unsafe { blst_fr_mul(&mut acc, &prev, base) };
+            }
+        }
+    }
+
+    acc
+}
+
+// `index`-th power of primitive `size`-th root of unity i.e. 7^((r - 1) / size),
+// where `size` is power of two
+fn domain_element(size: usize, index: usize) -> blst_scalar {
+    let shift = size.trailing_zeros();
+    let mut exp = [0u64; 4];
+    for (i, limb) in exp.iter_mut().enumerate() {
+        *limb = MODULUS_MINUS_ONE[i] >> shift;
+        if shift > 0 && i < 3 {
+            *limb |= MODULUS_MINUS_ONE[i + 1] << (64 - shift);
+        }
+    }
+
+    let root = pow(&fr_from_u64(GENERATOR), &exp);
+    let element = pow(&root, &[index as u64, 0, 0, 0]);
+
+    let mut out = blst_scalar::default();
+    unsafe { blst_scalar_from_fr(&mut out, &element) };
+    out
+}
+
+fn mul_p1(point: &blst_p1_affine, scalar: &blst_scalar) -> blst_p1 {
+    let mut p = blst_p1::default();
+    let mut out = blst_p1::default();
+    unsafe {
+        blst_p1_from_affine(&mut p, point);
+        blst_p1_mult(&mut out, &p, scalar.b.as_ptr(), 255);
+    }
+    out
+}
+
+fn neg_p1(point: &blst_p1) -> blst_p1 {
+    let mut out = *point;
+    unsafe { blst_p1_cneg(&mut out, true) };
+    out
+}
+
+fn add_p1(a: &blst_p1_affine, b: &blst_p1) -> blst_p1_affine {
+    let mut out = blst_p1::default();
+    let mut affine = blst_p1_affine::default();
+    unsafe {
+        blst_p1_add_or_double_affine(&mut out, b, a);
+        blst_p1_to_affine(&mut affine, &out);
+    }
+    affine
+}
+
+fn mul_p2(point: &blst_p2_affine, scalar: &blst_scalar) -> blst_p2 {
+    let mut p = blst_p2::default();
+    let mut out = blst_p2::default();
+    unsafe {
+        blst_p2_from_affine(&mut p, point);
+        blst_p2_mult(&mut out, &p, scalar.b.as_ptr(), 255);
+    }
+    out
+}
+
+fn neg_p2(point: &blst_p2) -> blst_p2 {
+    let mut out = *point;
+    unsafe { blst_p2_cneg(&mut out, true) };
+    out
+}
+
+fn add_p2(a: &blst_p2_affine, b: &blst_p2) -> blst_p2_affine {
+    let mut out = blst_p2::default();
+    let mut affine = blst_p2_affine::default();
+    unsafe {
+        blst_p2_add_or_double_affine(&mut out, b, a);
+        blst_p2_to_affine(&mut affine, &out);
+    }
+    affine
+}
+
+// e(p, q), or `None` when either point is at infinity i.e. pairing is one,
+// as miller loop isn't defined for points at infinity
+fn pairing(p: &blst_p1_affine, q: &blst_p2_affine) -> Option<blst_fp12> {
+    if unsafe { blst_p1_affine_is_inf(p) || blst_p2_affine_is_inf(q) } {
+        return None;
+    }
+
+    let mut miller = blst_fp12::default();
+    let mut out = blst_fp12::default();
+    unsafe {
+        blst_miller_loop(&mut miller, q, p);
+        blst_final_exp(&mut out, &miller);
+    }
+    Some(out)
+}
+
+// e(a, b) == e(c, d)
+fn pairings_equal(a: &blst_p1_affine, b: &blst_p2_affine, c: &blst_p1_affine, d: &blst_p2_affine) -> bool {
+    match (pairing(a, b), pairing(c, d)) {
+        (None, None) => true,
+        (Some(e), None) | (None, Some(e)) => unsafe { blst_fp12_is_one(&e) },
+        (Some(e), Some(f)) => unsafe { blst_fp12_is_equal(&e, &f) },
+    }
+}
@@ -0,0 +1,45 @@
+use std::convert::TryInto;
+
+use dusk_plonk::bls12_381::G1Affine;
+use dusk_plonk::commitment_scheme::kzg10;
+use dusk_plonk::fft::EvaluationDomain;
+
+use super::CellVerifier;
+use crate::{params, row};
+
+// Checks openings using dusk-plonk's KZG10 opening key, trimmed down
+// to domain size, from configured public parameters
+pub struct DuskVerifier;
+
+impl CellVerifier for DuskVerifier {
+    fn name(&self) -> &'static str {
+        "dusk-plonk"
+    }
+
+    fn verify_opening(&self, commitment: &[u8], opening: &[u8], point: usize, domain_size: usize) -> Result<bool, String> {
+        if opening.len() != 80 {
+            return Err(format!("opening of length {}, expected 80", opening.len()));
+        }
+        row::check_cols(domain_size)?;
+
+        let eval_domain = EvaluationDomain::new(domain_size).map_err(|e| format!("{:?}", e))?;
+        if point >= eval_domain.size() {
+            return Err(format!("point {} out of domain of {} elements", point, domain_size));
+        }
+
+        let (_, opening_key) = params::PUBLIC_PARAMS.trim(domain_size).map_err(|e| format!("{:?}", e))?;
+
+        let commitment_point = row::parse_commitment(commitment)?;
+        let witness_point = Option::from(G1Affine::from_compressed(opening[..48].try_into().unwrap()))
+            .ok_or_else(|| "witness is not a valid point".to_string())?;
+        let eval_point = row::parse_scalars(&opening[48..])?[0];
+
+        let proof = kzg10::Proof {
+            commitment_to_witness: kzg10::Commitment::from_affine(witness_point),
+            evaluated_point: eval_point,
+            commitment_to_polynomial: kzg10::Commitment::from_affine(commitment_point),
+        };
+
+        Ok(opening_key.check(eval_domain.elements().nth(point).unwrap(), proof))
+    }
+}
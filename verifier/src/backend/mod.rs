@@ -0,0 +1,48 @@
+// Pairing backend, cell openings are checked with, kept behind `CellVerifier`
+// so that callers don't depend on any particular pairing library
+//
+// dusk-plonk backend is always built, while blst one is built only with `blst`
+// feature, in which case it's used in place of dusk-plonk
+
+mod dusk;
+#[cfg(feature = "blst")]
+mod blst;
+
+pub use self::dusk::DuskVerifier;
+
+#[cfg(feature = "blst")]
+pub use self::blst::BlstVerifier;
+
+#[cfg(feature = "blst")]
+use crate::params;
+
+pub trait CellVerifier: Send + Sync {
+    // Name of backend, for logs
+    fn name(&self) -> &'static str;
+
+    // Checks 80 bytes opening ( witness + evaluation ) of cell, opened at
+    // `point`-th element of evaluation domain of `domain_size` elements,
+    // against 48 bytes commitment, failing on malformed input
+    fn verify_opening(&self, commitment: &[u8], opening: &[u8], point: usize, domain_size: usize)
+        -> Result<bool, String>;
+}
+
+lazy_static! {
+    // backend selected at build time, set up from configured public parameters
+    static ref VERIFIER: Box<dyn CellVerifier> = selected();
+}
+
+#[cfg(not(feature = "blst"))]
+fn selected() -> Box<dyn CellVerifier> {
+    Box::new(DuskVerifier)
+}
+
+#[cfg(feature = "blst")]
+fn selected() -> Box<dyn CellVerifier> {
+    Box::new(BlstVerifier::from_params(&params::PUBLIC_PARAMS).expect("opening key to be valid"))
+}
+
+// Backend all cell openings are checked with
+pub fn get() -> &'static dyn CellVerifier {
+    VERIFIER.as_ref()
+}
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use clap::{App, Arg, ArgMatches};
+
+use verifier::mock_node::{Misbehaviour, MockConfig, MockNode};
+
+fn parse<T: FromStr>(matches: &ArgMatches, name: &str) -> T
+where
+    T::Err: Display,
+{
+    let v = matches.value_of(name).unwrap();
+    v.parse().unwrap_or_else(|e| panic!("invalid value `{}` for --{} : {}", v, name, e))
+}
+
+fn main() {
+    let flag = |name: &'static str, help: &'static str| Arg::with_name(name).long(name).takes_value(true).help(help);
+
+    let matches = App::new("mock-node")
+        .about("Mock full node, serving chain & kate RPC, for testing light client")
+        .arg(flag("address", "Address WebSocket RPC is served at").default_value("127.0.0.1:9944"))
+        .arg(flag("rows", "Rows of original data matrix, of each block").default_value("4"))
+        .arg(flag("cols", "Columns of data matrix, of each block").default_value("8"))
+        .arg(flag("block-time-ms", "Interval at which blocks are produced").default_value("6000"))
+        .arg(flag("seed", "Seed, data matrices are derived from").default_value("0"))
+        .arg(flag("withhold", "Fraction of cells, proofs of which are never served"))
+        .arg(flag("bad-proofs", "Fraction of cells, proofs of which carry wrong evaluation"))
+        .arg(
+            Arg::with_name("wrong-dimensions")
+                .long("wrong-dimensions")
+                .help("Advertise more rows in header than committed to"),
+        )
+        .get_matches();
+
+    let misbehaviour = if matches.is_present("wrong-dimensions") {
+        Misbehaviour::WrongDimensions
+    } else if matches.is_present("withhold") {
+        Misbehaviour::WithholdCells(parse(&matches, "withhold"))
+    } else if matches.is_present("bad-proofs") {
+        Misbehaviour::BadProofs(parse(&matches, "bad-proofs"))
+    } else {
+        Misbehaviour::Honest
+    };
+
+    let addr: SocketAddr = parse(&matches, "address");
+    let config = MockConfig {
+        rows: parse(&matches, "rows"),
+        cols: parse(&matches, "cols"),
+        block_time: Some(Duration::from_millis(parse(&matches, "block-time-ms"))),
+        seed: parse(&matches, "seed"),
+        misbehaviour,
+    };
+
+    let node = MockNode::start(addr, config).unwrap_or_else(|e| panic!("failed to start mock node : {}", e));
+    println!("🧪 Mock node serving @ {} ( {:?} )", node.uri(), misbehaviour);
+
+    loop {
+        thread::park();
+    }
+}
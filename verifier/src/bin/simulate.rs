@@ -0,0 +1,64 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use clap::{App, Arg, ArgMatches};
+
+use verifier::simulation::{run, SimulationConfig};
+
+// Parses comma separated list of values of flag
+fn parse_list<T: FromStr>(matches: &ArgMatches, name: &str) -> Vec<T>
+where
+    T::Err: Display,
+{
+    matches
+        .value_of(name)
+        .unwrap()
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value `{}` for --{} : {}", v, name, e))
+        })
+        .collect()
+}
+
+fn main() {
+    let flag = |name: &'static str, help: &'static str| Arg::with_name(name).long(name).takes_value(true).help(help);
+
+    let matches = App::new("simulate")
+        .about("Simulates data withholding & compares detection rate with computed confidence")
+        .arg(flag("rows", "Rows of original data matrix").default_value("8"))
+        .arg(flag("cols", "Columns of data matrix").default_value("16"))
+        .arg(flag("withheld", "Comma separated fractions of extended cells withheld").default_value("0.1,0.25,0.5"))
+        .arg(flag("samples", "Comma separated cell counts sampled per block").default_value("5,10,15"))
+        .arg(flag("trials", "Trials run for each combination").default_value("1000"))
+        .arg(flag("blocks", "Distinct blocks trials are spread over").default_value("4"))
+        .arg(flag("seed", "Seed, blocks & sampling are derived from").default_value("0"))
+        .arg(Arg::with_name("json").long("json").help("Print reports as JSON lines"))
+        .get_matches();
+
+    let single = |name: &str| parse_list::<u64>(&matches, name)[0];
+
+    for withheld_fraction in parse_list::<f64>(&matches, "withheld") {
+        for sample_count in parse_list::<usize>(&matches, "samples") {
+            let config = SimulationConfig {
+                rows: single("rows") as usize,
+                cols: single("cols") as usize,
+                withheld_fraction,
+                sample_count,
+                trials: single("trials") as usize,
+                blocks: single("blocks") as usize,
+                seed: single("seed"),
+            };
+
+            match run(&config) {
+                Ok(report) if matches.is_present("json") => println!("{}", serde_json::to_string(&report).unwrap()),
+                Ok(report) => println!("{}", report),
+                Err(e) => {
+                    eprintln!("❌  Simulation failed : {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use lru::LruCache;
+
+use crate::chain::Hash;
+use crate::metrics;
+
+// Cell of some block, identified by block hash, row & column
+pub type Key = (Hash, u32, u32);
+
+// Memoises proofs ( witness + evaluation = 48 + 32 bytes ) of cells, which
+// are already verified, so that they're neither refetched nor reverified
+pub struct ProofCache {
+    entries: LruCache<Key, [u8; 80]>,
+}
+
+impl ProofCache {
+    // Least recently used entries are evicted, once `capacity` is reached
+    pub fn new(capacity: usize) -> ProofCache {
+        ProofCache {
+            entries: LruCache::new(capacity),
+        }
+    }
+
+    pub fn insert(&mut self, key: Key, proof: [u8; 80]) {
+        self.entries.put(key, proof);
+        metrics::CACHE_ENTRIES.set(self.entries.len() as i64);
+    }
+
+    pub fn get(&mut self, key: &Key) -> Option<[u8; 80]> {
+        match self.entries.get(key) {
+            Some(proof) => {
+                metrics::CACHE_HITS.inc();
+                Some(*proof)
+            }
+            None => {
+                metrics::CACHE_MISSES.inc();
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::{BTreeMap, HashMap};
+
+use codec::{Compact, Decode, Error, Input};
+
+use crate::app_data::DataLookup;
+use crate::config;
+
+// At max these many latest headers are kept in memory, older
+// ones get pruned as chain grows
+pub const MAX_HEADERS: u64 = 4096;
+
+pub type Hash = [u8; 32];
+
+// Header as served by full node, hash is computed over SCALE encoded
+// header, as seen on wire
+#[derive(Clone, Debug)]
+pub struct Header {
+    pub hash: Hash,
+    pub parent_hash: Hash,
+    pub number: u64,
+    pub state_root: Hash,
+    pub extrinsics_root: ExtrinsicsRoot,
+    pub logs: Vec<DigestItem>,
+    // absent, unless headers are configured to carry it
+    pub app_data_lookup: Option<DataLookup>,
+}
+
+// Extended extrinsics root, carrying kate commitment of data matrix
+#[derive(Clone, Debug)]
+pub struct ExtrinsicsRoot {
+    pub hash: Hash,
+    pub commitment: Vec<u8>,
+    pub rows: u16,
+    pub cols: u16,
+}
+
+// Digest log entries, only consensus messages are of interest to
+// light client, rest are just skipped over
+#[derive(Clone, Debug)]
+pub enum DigestItem {
+    Consensus([u8; 4], Vec<u8>),
+    Other,
+}
+
+impl Decode for ExtrinsicsRoot {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        Ok(ExtrinsicsRoot {
+            hash: <Hash>::decode(input)?,
+            commitment: <Vec<u8>>::decode(input)?,
+            rows: u16::decode(input)?,
+            cols: u16::decode(input)?,
+        })
+    }
+}
+
+impl Decode for DigestItem {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        match input.read_byte()? {
+            // Other
+            0 => {
+                <Vec<u8>>::decode(input)?;
+                Ok(DigestItem::Other)
+            }
+            // ChangesTrieRoot
+            2 => {
+                <Hash>::decode(input)?;
+                Ok(DigestItem::Other)
+            }
+            // Consensus
+            4 => Ok(DigestItem::Consensus(<[u8; 4]>::decode(input)?, <Vec<u8>>::decode(input)?)),
+            // Seal, PreRuntime
+            5 | 6 => {
+                <([u8; 4], Vec<u8>)>::decode(input)?;
+                Ok(DigestItem::Other)
+            }
+            // ChangesTrieSignal
+            7 => {
+                <(u8, Option<(u32, u32)>)>::decode(input)?;
+                Ok(DigestItem::Other)
+            }
+            // RuntimeEnvironmentUpdated
+            8 => Ok(DigestItem::Other),
+            _ => Err("unknown digest item".into()),
+        }
+    }
+}
+
+impl Header {
+    // Decodes SCALE encoded header & computes its blake2 256 hash
+    pub fn decode(encoded: &[u8]) -> Option<Header> {
+        let mut input = encoded;
+
+        let header = Header::decode_from(&mut input)?;
+        if !input.is_empty() {
+            return None;
+        }
+
+        Some(header)
+    }
+
+    // Decodes one header from start of input, advancing it, so that
+    // headers can be read out of other encoded structures too
+    pub fn decode_from(input: &mut &[u8]) -> Option<Header> {
+        let encoded = *input;
+
+        let parent_hash = <Hash>::decode(input).ok()?;
+        let number = <Compact<u32>>::decode(input).ok()?.0 as u64;
+        let state_root = <Hash>::decode(input).ok()?;
+        let extrinsics_root = ExtrinsicsRoot::decode(input).ok()?;
+        let logs = <Vec<DigestItem>>::decode(input).ok()?;
+        let app_data_lookup = if config::app_data_lookup() {
+            Some(DataLookup::decode(input).ok()?)
+        } else {
+            None
+        };
+
+        let consumed = &encoded[..encoded.len() - input.len()];
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(blake2_rfc::blake2b::blake2b(32, &[], consumed).as_bytes());
+
+        Some(Header {
+            hash,
+            parent_hash,
+            number,
+            state_root,
+            extrinsics_root,
+            logs,
+            app_data_lookup,
+        })
+    }
+}
+
+// Outcome of importing one header into chain
+#[derive(Debug, PartialEq)]
+pub enum Import {
+    // header extends current canonical head
+    Extended,
+    // header is new canonical head, but on a different branch,
+    // all canonical blocks above `ancestor` are retracted
+    Reorg { ancestor: u64 },
+    // header was already imported
+    Known,
+    // header's parent is not known, it can't be linked
+    UnknownParent,
+    // header's number doesn't follow parent's number
+    BadNumber,
+}
+
+// Maintains tree of headers seen by light client, rooted at first imported
+// header, along with canonical chain i.e. number -> hash index, following
+// latest head announced by full node
+#[derive(Default)]
+pub struct HeaderChain {
+    headers: HashMap<Hash, Header>,
+    canonical: BTreeMap<u64, Hash>,
+    head: Option<Hash>,
+}
+
+impl HeaderChain {
+    pub fn new() -> HeaderChain {
+        HeaderChain::default()
+    }
+
+    // Imports header, which is announced as new chain head, after making sure
+    // it links to some already known header
+    //
+    // @note Very first imported header is trusted as anchor of chain
+    pub fn import(&mut self, header: Header) -> Import {
+        if self.headers.contains_key(&header.hash) {
+            return Import::Known;
+        }
+
+        let head = match self.head {
+            Some(head) => head,
+            None => {
+                self.canonical.insert(header.number, header.hash);
+                self.head = Some(header.hash);
+                self.headers.insert(header.hash, header);
+                return Import::Extended;
+            }
+        };
+
+        let parent = match self.headers.get(&header.parent_hash) {
+            Some(parent) => parent,
+            None => return Import::UnknownParent,
+        };
+
+        if parent.number + 1 != header.number {
+            return Import::BadNumber;
+        }
+
+        let outcome = if header.parent_hash == head {
+            self.canonical.insert(header.number, header.hash);
+            Import::Extended
+        } else {
+            match self.switch_to(&header) {
+                Some(ancestor) => Import::Reorg { ancestor },
+                None => return Import::UnknownParent,
+            }
+        };
+
+        self.head = Some(header.hash);
+        self.headers.insert(header.hash, header);
+        self.prune();
+
+        outcome
+    }
+
+    // Rewrites canonical index so that it follows branch ending at `header`,
+    // returns number of common ancestor of old & new branch
+    //
+    // `None` is returned, leaving canonical index untouched, when branch
+    // doesn't meet canonical chain, before running into a header which is
+    // unknown or already pruned
+    fn switch_to(&mut self, header: &Header) -> Option<u64> {
+        let mut branch = vec![(header.number, header.hash)];
+        let mut cursor = header.parent_hash;
+
+        let ancestor = loop {
+            let h = self.headers.get(&cursor)?;
+            if self.canonical.get(&h.number) == Some(&h.hash) {
+                break h.number;
+            }
+
+            branch.push((h.number, h.hash));
+            cursor = h.parent_hash;
+        };
+
+        let retracted: Vec<u64> = self.canonical.range(ancestor + 1..).map(|(n, _)| *n).collect();
+        for n in retracted {
+            self.canonical.remove(&n);
+        }
+
+        for (n, h) in branch {
+            self.canonical.insert(n, h);
+        }
+
+        Some(ancestor)
+    }
+
+    // Drops headers which are too far behind head, so that memory usage
+    // stays bounded
+    fn prune(&mut self) {
+        let head = match self.head.and_then(|h| self.headers.get(&h)) {
+            Some(h) => h.number,
+            None => return,
+        };
+
+        if head < MAX_HEADERS {
+            return;
+        }
+
+        let lowest = head - MAX_HEADERS;
+        self.headers.retain(|_, h| h.number > lowest);
+        self.canonical = self.canonical.split_off(&(lowest + 1));
+    }
+
+    pub fn head(&self) -> Option<&Header> {
+        self.head.and_then(|h| self.headers.get(&h))
+    }
+
+    // Canonical block hash at given height, if it's within tracked window
+    pub fn canonical_hash(&self, number: u64) -> Option<&Hash> {
+        self.canonical.get(&number)
+    }
+
+    // Whether given (number, hash) pair is part of canonical chain, `None` is
+    // returned when block number falls outside of tracked window
+    pub fn is_canonical(&self, number: u64, hash: &Hash) -> Option<bool> {
+        self.canonical_hash(number).map(|h| h == hash)
+    }
+
+    // Hash of block at given height, on branch ending at block `hash`, `None`
+    // is returned when branch runs into a header, which is unknown or already
+    // pruned, before reaching that height
+    pub fn ancestor_at(&self, hash: &Hash, number: u64) -> Option<Hash> {
+        let mut header = self.headers.get(hash)?;
+        while header.number > number {
+            header = self.headers.get(&header.parent_hash)?;
+        }
+
+        if header.number == number {
+            Some(header.hash)
+        } else {
+            None
+        }
+    }
+}
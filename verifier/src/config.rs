@@ -0,0 +1,351 @@
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use clap::{App, Arg, ArgMatches};
+use libp2p::Multiaddr;
+use serde::{Deserialize, Serialize};
+
+use crate::params;
+
+lazy_static! {
+    // configuration light client was started with, set once at startup
+    static ref CONFIG: RwLock<Config> = RwLock::new(Config::default());
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(format!("unknown log level `{}`, expected one of error, warn, info, debug, trace", s)),
+        }
+    }
+}
+
+// Light client configuration, resolved in order of increasing precedence,
+// from defaults, TOML file, environment variables & command line flags
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // full node websocket endpoints, first one is followed for chain tip
+    pub endpoints: Vec<String>,
+    // public parameters, in `srs_format`, embedded dusk ones are used if not set
+    pub srs_path: Option<PathBuf>,
+    pub srs_format: params::SrsFormat,
+    // cells sampled per round, for each block
+    pub sample_count: usize,
+    // confidence ( out of 100 ), block is sampled till
+    pub target_confidence: f64,
+    pub max_sampling_rounds: usize,
+    pub max_retries: usize,
+    pub retry_delay_ms: u64,
+    // threads used for verifying cell proofs
+    pub concurrency: usize,
+    // verified cell proofs kept in memory
+    pub cache_size: usize,
+    // directory where light client keeps its data
+    pub storage_path: PathBuf,
+    // address HTTP API is served at
+    pub api_address: String,
+    pub metrics_port: u16,
+    // port verified cells are shared with other light clients at,
+    // peer to peer network isn't joined when not set
+    pub p2p_port: Option<u16>,
+    // multiaddrs of known light clients, ending with `/p2p/<peer id>`
+    pub bootstrap_peers: Vec<String>,
+    // application data is fetched one row at a time, using multiproofs
+    // of `kate_queryMultiProof`, instead of one proof per cell
+    pub multiproof: bool,
+    // headers carry application data index ( `appDataLookup` ) as their last
+    // field, which isn't the case for chains predating application data
+    pub app_data_lookup: bool,
+    pub log_level: LogLevel,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            endpoints: vec!["ws://localhost:9944".to_string()],
+            srs_path: None,
+            srs_format: params::SrsFormat::Dusk,
+            sample_count: 10,
+            target_confidence: 99.9,
+            max_sampling_rounds: 3,
+            max_retries: 3,
+            retry_delay_ms: 1000,
+            concurrency: num_cpus::get(),
+            cache_size: 16384,
+            storage_path: PathBuf::from("data"),
+            api_address: "0.0.0.0:7000".to_string(),
+            metrics_port: 9615,
+            p2p_port: None,
+            bootstrap_peers: vec![],
+            multiproof: false,
+            app_data_lookup: true,
+            log_level: LogLevel::Info,
+        }
+    }
+}
+
+// Reads environment variable & parses it, `None` if it's not set
+fn from_env<T: FromStr>(name: &str) -> Result<Option<T>, String>
+where
+    T::Err: ToString,
+{
+    match env::var(name) {
+        Ok(v) if !v.trim().is_empty() => v
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e: T::Err| format!("invalid value `{}` for environment variable {} : {}", v, name, e.to_string())),
+        _ => Ok(None),
+    }
+}
+
+// Parses value of command line flag, `None` if it's not passed
+fn from_flag<T: FromStr>(matches: &ArgMatches, name: &str) -> Result<Option<T>, String>
+where
+    T::Err: ToString,
+{
+    match matches.value_of(name) {
+        Some(v) => v
+            .parse()
+            .map(Some)
+            .map_err(|e: T::Err| format!("invalid value `{}` for --{} : {}", v, name, e.to_string())),
+        None => Ok(None),
+    }
+}
+
+fn app<'a, 'b>() -> App<'a, 'b> {
+    let flag = |name: &'a str, help: &'a str| Arg::with_name(name).long(name).takes_value(true).help(help);
+
+    App::new("da-light-client")
+        .about("Light client for Data Availability Blockchain")
+        .arg(flag("config", "TOML configuration file"))
+        .arg(flag("endpoint", "Full node websocket endpoint, can be repeated").multiple(true).number_of_values(1))
+        .arg(flag("srs-path", "File with serialised public parameters"))
+        .arg(flag("srs-format", "Format of public parameters & cell openings, one of dusk, ethereum"))
+        .arg(flag("sample-count", "Cells sampled per round, for each block"))
+        .arg(flag("target-confidence", "Confidence ( out of 100 ), block is sampled till"))
+        .arg(flag("max-sampling-rounds", "Sampling rounds attempted for reaching target confidence"))
+        .arg(flag("max-retries", "Retries for failed proof fetches"))
+        .arg(flag("retry-delay-ms", "Delay before first retry, doubled after each attempt"))
+        .arg(flag("concurrency", "Threads used for verifying cell proofs"))
+        .arg(flag("cache-size", "Verified cell proofs kept in memory"))
+        .arg(flag("storage-path", "Directory where light client keeps its data"))
+        .arg(flag("api-address", "Address HTTP API is served at"))
+        .arg(flag("metrics-port", "Port metrics are served at"))
+        .arg(flag("p2p-port", "Port verified cells are shared with other light clients at"))
+        .arg(flag("bootstrap-peer", "Multiaddr of known light client, can be repeated").multiple(true).number_of_values(1))
+        .arg(flag("multiproof", "Fetch application data using one multiproof per row, true or false"))
+        .arg(flag("app-data-lookup", "Whether headers carry application data index, true or false"))
+        .arg(flag("log-level", "One of error, warn, info, debug, trace"))
+}
+
+macro_rules! override_with {
+    ($field:expr, $value:expr) => {
+        if let Some(v) = $value? {
+            $field = v;
+        }
+    };
+}
+
+impl Config {
+    // Resolves configuration from given command line arguments ( without
+    // program name ), TOML file pointed to by `--config` & environment
+    pub fn load(args: Vec<String>) -> Result<Config, String> {
+        let matches = app()
+            .get_matches_from_safe(std::iter::once("da-light-client".to_string()).chain(args))
+            .map_err(|e| e.message)?;
+
+        let mut config = match matches.value_of("config") {
+            Some(path) => {
+                let content = fs::read_to_string(path).map_err(|e| format!("failed to read config file {} : {}", path, e))?;
+                toml::from_str(&content).map_err(|e| format!("invalid config file {} : {}", path, e))?
+            }
+            None => Config::default(),
+        };
+
+        // environment variables, names are kept same as in `.env` file
+        if let Some(v) = from_env::<String>("WSURI")? {
+            config.endpoints = v.split(',').map(|e| e.trim().to_string()).filter(|e| !e.is_empty()).collect();
+        }
+        override_with!(config.sample_count, from_env("AskProofCount"));
+        override_with!(config.target_confidence, from_env("TargetConfidence"));
+        override_with!(config.max_sampling_rounds, from_env("MaxSamplingRounds"));
+        override_with!(config.max_retries, from_env("MaxRetries"));
+        override_with!(config.retry_delay_ms, from_env("RetryDelay"));
+        override_with!(config.concurrency, from_env("Concurrency"));
+        override_with!(config.cache_size, from_env("CacheSize"));
+        override_with!(config.storage_path, from_env("StoragePath"));
+        override_with!(config.api_address, from_env("ApiAddress"));
+        override_with!(config.metrics_port, from_env("MetricsPort"));
+        override_with!(config.multiproof, from_env("MultiProof"));
+        override_with!(config.app_data_lookup, from_env("AppDataLookup"));
+        override_with!(config.srs_format, from_env("SRSFormat"));
+        override_with!(config.log_level, from_env("LogLevel"));
+        if let Some(v) = from_env::<PathBuf>("SRSPath")? {
+            config.srs_path = Some(v);
+        }
+        if let Some(v) = from_env::<u16>("P2PPort")? {
+            config.p2p_port = Some(v);
+        }
+        if let Some(v) = from_env::<String>("BootstrapPeers")? {
+            config.bootstrap_peers = v.split(',').map(|e| e.trim().to_string()).filter(|e| !e.is_empty()).collect();
+        }
+        if let Some(port) = from_env::<u16>("PORT")? {
+            let addr: SocketAddr = config
+                .api_address
+                .parse()
+                .map_err(|e| format!("invalid api address `{}` : {}", config.api_address, e))?;
+            config.api_address = SocketAddr::new(addr.ip(), port).to_string();
+        }
+
+        // command line flags
+        if let Some(v) = matches.values_of("endpoint") {
+            config.endpoints = v.map(|e| e.to_string()).collect();
+        }
+        override_with!(config.sample_count, from_flag(&matches, "sample-count"));
+        override_with!(config.target_confidence, from_flag(&matches, "target-confidence"));
+        override_with!(config.max_sampling_rounds, from_flag(&matches, "max-sampling-rounds"));
+        override_with!(config.max_retries, from_flag(&matches, "max-retries"));
+        override_with!(config.retry_delay_ms, from_flag(&matches, "retry-delay-ms"));
+        override_with!(config.concurrency, from_flag(&matches, "concurrency"));
+        override_with!(config.cache_size, from_flag(&matches, "cache-size"));
+        override_with!(config.storage_path, from_flag(&matches, "storage-path"));
+        override_with!(config.api_address, from_flag(&matches, "api-address"));
+        override_with!(config.metrics_port, from_flag(&matches, "metrics-port"));
+        override_with!(config.multiproof, from_flag(&matches, "multiproof"));
+        override_with!(config.app_data_lookup, from_flag(&matches, "app-data-lookup"));
+        override_with!(config.srs_format, from_flag(&matches, "srs-format"));
+        override_with!(config.log_level, from_flag(&matches, "log-level"));
+        if let Some(v) = from_flag::<PathBuf>(&matches, "srs-path")? {
+            config.srs_path = Some(v);
+        }
+        if let Some(v) = from_flag::<u16>(&matches, "p2p-port")? {
+            config.p2p_port = Some(v);
+        }
+        if let Some(v) = matches.values_of("bootstrap-peer") {
+            config.bootstrap_peers = v.map(|e| e.to_string()).collect();
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    // Checks all values are sane, so that light client doesn't fail
+    // halfway through, because of misconfiguration
+    //
+    // Nothing is touched on disk, that's left to startup, see `create_storage`
+    pub fn validate(&self) -> Result<(), String> {
+        if self.endpoints.is_empty() {
+            return Err("at least one full node endpoint must be configured".to_string());
+        }
+
+        for endpoint in self.endpoints.iter() {
+            if !(endpoint.starts_with("ws://") || endpoint.starts_with("wss://")) {
+                return Err(format!("endpoint `{}` must be a websocket URI i.e. ws:// or wss://", endpoint));
+            }
+        }
+
+        // public parameters themselves are loaded at startup, see `params::init`
+        if self.srs_format == params::SrsFormat::Ethereum && self.srs_path.is_none() {
+            return Err("SRS file must be configured for ethereum format".to_string());
+        }
+
+        // multiproofs are only handled in dusk encoding, see `params::require_dusk`
+        if self.srs_format == params::SrsFormat::Ethereum && self.multiproof {
+            return Err("multiproofs can't be used with ethereum SRS format".to_string());
+        }
+
+        // verifier reports count of verified cells as `u8`
+        if self.sample_count == 0 || self.sample_count > u8::MAX as usize {
+            return Err(format!("sample count must be in [1, {}], found {}", u8::MAX, self.sample_count));
+        }
+
+        if !(self.target_confidence > 0.0 && self.target_confidence < 100.0) {
+            return Err(format!("target confidence must be in (0, 100), found {}", self.target_confidence));
+        }
+
+        if self.max_sampling_rounds == 0 {
+            return Err("max sampling rounds must be at least 1".to_string());
+        }
+
+        if self.concurrency == 0 {
+            return Err("concurrency must be at least 1".to_string());
+        }
+
+        if self.cache_size == 0 {
+            return Err("cache size must be at least 1".to_string());
+        }
+
+        let addr: SocketAddr = self
+            .api_address
+            .parse()
+            .map_err(|e| format!("invalid api address `{}` : {}", self.api_address, e))?;
+
+        if addr.port() == self.metrics_port {
+            return Err(format!("api & metrics can't both be served on port {}", self.metrics_port));
+        }
+
+        if let Some(port) = self.p2p_port {
+            if port == addr.port() || port == self.metrics_port {
+                return Err(format!("p2p port {} is already used for serving api or metrics", port));
+            }
+        }
+
+        for peer in self.bootstrap_peers.iter() {
+            peer.parse::<Multiaddr>()
+                .map_err(|e| format!("invalid bootstrap peer `{}` : {}", peer, e))?;
+        }
+
+        Ok(())
+    }
+
+    // Creates storage directory, unless it's already there
+    pub fn create_storage(&self) -> Result<(), String> {
+        if self.storage_path.exists() && !self.storage_path.is_dir() {
+            return Err(format!("storage path {} is not a directory", self.storage_path.display()));
+        }
+
+        fs::create_dir_all(&self.storage_path)
+            .map_err(|e| format!("failed to create storage directory {} : {}", self.storage_path.display(), e))
+    }
+}
+
+// Makes given configuration visible to whole verifier
+pub fn set(config: Config) {
+    *CONFIG.write().unwrap() = config;
+}
+
+pub fn get() -> Config {
+    CONFIG.read().unwrap().clone()
+}
+
+pub fn log_enabled(level: LogLevel) -> bool {
+    CONFIG.read().unwrap().log_level >= level
+}
+
+// Whether headers are to be decoded along with application data index
+pub fn app_data_lookup() -> bool {
+    CONFIG.read().unwrap().app_data_lookup
+}
@@ -0,0 +1,135 @@
+// SRS of Ethereum KZG ceremony & encoding of openings, as of EIP-4844, so that
+// commitments produced with it can be verified alongside dusk-plonk ones
+//
+// SRS is JSON, as published by consensus specs, holding `0x` prefixed hex
+// encoded points, compressed big endian, same as dusk-plonk's, G1 ones both in
+// monomial & Lagrange form & G2 ones in monomial form
+//
+// Openings differ from dusk-plonk ones in two ways, evaluation is encoded big
+// endian & blob elements are laid over evaluation domain in bit reversed order,
+// so `i`-th element is opened at `reverse_bits(i)`-th domain point
+
+use std::convert::TryInto;
+
+use dusk_plonk::bls12_381::{G1Affine, G1Projective, G2Affine, G2Prepared};
+use dusk_plonk::commitment_scheme::kzg10::{CommitKey, OpeningKey, PublicParameters};
+use serde::Deserialize;
+
+use crate::backend;
+
+#[derive(Deserialize)]
+struct TrustedSetup {
+    g1_monomial: Vec<String>,
+    // only used for cross checking monomial points, when present
+    #[serde(default)]
+    g1_lagrange: Vec<String>,
+    g2_monomial: Vec<String>,
+}
+
+fn decode(point: &str, len: usize) -> Result<Vec<u8>, String> {
+    let bytes = hex::decode(point.trim_start_matches("0x")).map_err(|e| e.to_string())?;
+    if bytes.len() != len {
+        return Err(format!("point of length {}, expected {}", bytes.len(), len));
+    }
+
+    Ok(bytes)
+}
+
+fn parse_g1(point: &str) -> Result<G1Affine, String> {
+    let bytes: [u8; 48] = decode(point, 48)?.as_slice().try_into().unwrap();
+    Option::from(G1Affine::from_compressed(&bytes)).ok_or_else(|| "not a valid point".to_string())
+}
+
+fn parse_g2(point: &str) -> Result<G2Affine, String> {
+    let bytes: [u8; 96] = decode(point, 96)?.as_slice().try_into().unwrap();
+    Option::from(G2Affine::from_compressed(&bytes)).ok_or_else(|| "not a valid point".to_string())
+}
+
+fn parse_all<T>(points: &[String], name: &str, parse: fn(&str) -> Result<T, String>) -> Result<Vec<T>, String> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| parse(point).map_err(|e| format!("{}[{}] : {}", name, i, e)))
+        .collect()
+}
+
+// Builds public parameters out of JSON SRS, powers of τ in G1 become commit
+// key, while first two powers in G2 make up opening key
+pub fn parse_srs(bytes: &[u8]) -> Result<PublicParameters, String> {
+    let setup: TrustedSetup =
+        serde_json::from_slice(bytes).map_err(|e| format!("failed to deserialise ethereum SRS : {}", e))?;
+
+    let powers_of_g = parse_all(&setup.g1_monomial, "g1_monomial", parse_g1)?;
+    if powers_of_g.len() < 2 {
+        return Err(format!("expected at least 2 G1 points, found {}", powers_of_g.len()));
+    }
+
+    let g2 = parse_all(&setup.g2_monomial, "g2_monomial", parse_g2)?;
+    if g2.len() < 2 {
+        return Err(format!("expected at least 2 G2 points, found {}", g2.len()));
+    }
+
+    // Lagrange basis polynomials sum up to 1, so do their commitments to g
+    if !setup.g1_lagrange.is_empty() {
+        let lagrange = parse_all(&setup.g1_lagrange, "g1_lagrange", parse_g1)?;
+        if lagrange.len() != powers_of_g.len() {
+            return Err(format!(
+                "expected {} G1 Lagrange points, found {}",
+                powers_of_g.len(),
+                lagrange.len()
+            ));
+        }
+
+        let sum = lagrange.iter().fold(G1Projective::identity(), |acc, point| acc + G1Projective::from(point));
+        if G1Affine::from(sum) != powers_of_g[0] {
+            return Err("G1 Lagrange points don't match monomial ones".to_string());
+        }
+    }
+
+    let (h, beta_h) = (g2[0], g2[1]);
+    Ok(PublicParameters {
+        opening_key: OpeningKey {
+            g: powers_of_g[0],
+            h,
+            beta_h,
+            prepared_h: G2Prepared::from(h),
+            prepared_beta_h: G2Prepared::from(beta_h),
+        },
+        commit_key: CommitKey { powers_of_g },
+    })
+}
+
+// Position of `index` in evaluation domain of `size` elements, which is
+// power of two
+pub fn reverse_bits(index: usize, size: usize) -> usize {
+    let bits = size.trailing_zeros();
+    if bits == 0 {
+        return index;
+    }
+
+    index.reverse_bits() >> (std::mem::size_of::<usize>() as u32 * 8 - bits)
+}
+
+// Rewrites 80 bytes opening of `index`-th element, in Ethereum encoding, as
+// dusk-plonk encoded one, along with domain point it's opened at
+pub fn to_native(opening: &[u8], index: usize, domain_size: usize) -> Result<(Vec<u8>, usize), String> {
+    if opening.len() != 80 {
+        return Err(format!("opening of length {}, expected 80", opening.len()));
+    }
+
+    let size = domain_size.next_power_of_two();
+    if index >= size {
+        return Err(format!("point {} out of domain of {} elements", index, domain_size));
+    }
+
+    let mut native = opening.to_vec();
+    native[48..].reverse();
+    Ok((native, reverse_bits(index, size)))
+}
+
+// Checks Ethereum encoded opening of `index`-th element, against
+// 48 bytes commitment
+pub fn verify_opening(commitment: &[u8], opening: &[u8], index: usize, domain_size: usize) -> Result<bool, String> {
+    let (opening, point) = to_native(opening, index, domain_size)?;
+    backend::get().verify_opening(commitment, &opening, point, domain_size)
+}
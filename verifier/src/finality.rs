@@ -0,0 +1,277 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+use codec::{Compact, Decode, Encode};
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+
+use crate::chain::{DigestItem, Hash, Header, HeaderChain};
+
+// GRANDPA's consensus engine id, used in header digest
+const GRANDPA_ENGINE_ID: [u8; 4] = *b"FRNK";
+
+pub type AuthorityId = [u8; 32];
+
+// Authorities along with their voting weight
+pub type AuthorityList = Vec<(AuthorityId, u64)>;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    // justification couldn't be decoded
+    Malformed,
+    // authority set is yet to be initialised
+    NoAuthoritySet,
+    // precommit signed by someone not in current set
+    UnknownAuthority,
+    // precommit signature doesn't verify
+    BadSignature,
+    // same authority precommitted more than once
+    DuplicateVote,
+    // precommit target doesn't descend from commit target
+    NotDescendant,
+    // valid precommits don't reach supermajority
+    InsufficientWeight,
+    // authority set is empty or some authority carries no weight
+    InvalidAuthoritySet,
+}
+
+#[derive(Decode)]
+struct Precommit {
+    target_hash: Hash,
+    target_number: u32,
+}
+
+#[derive(Decode)]
+struct SignedPrecommit {
+    precommit: Precommit,
+    signature: [u8; 64],
+    id: AuthorityId,
+}
+
+#[derive(Decode)]
+struct Commit {
+    target_hash: Hash,
+    target_number: u32,
+    precommits: Vec<SignedPrecommit>,
+}
+
+// SCALE encoded GRANDPA justification, as served by full node
+struct Justification {
+    round: u64,
+    commit: Commit,
+    votes_ancestries: Vec<Header>,
+}
+
+impl Justification {
+    fn decode(encoded: &[u8]) -> Option<Justification> {
+        let mut input = encoded;
+
+        let round = u64::decode(&mut input).ok()?;
+        let commit = Commit::decode(&mut input).ok()?;
+
+        let count = <Compact<u32>>::decode(&mut input).ok()?.0;
+        let mut votes_ancestries = Vec::new();
+        for _ in 0..count {
+            votes_ancestries.push(Header::decode_from(&mut input)?);
+        }
+
+        if !input.is_empty() {
+            return None;
+        }
+
+        Some(Justification {
+            round,
+            commit,
+            votes_ancestries,
+        })
+    }
+}
+
+// Authority set change, signalled in some header, which is to
+// be enacted when block at `effective` gets finalized
+struct PendingChange {
+    signalled_in: (u64, Hash),
+    effective: u64,
+    authorities: AuthorityList,
+}
+
+// Tracks GRANDPA authority set & verifies justifications against it,
+// keeping note of latest finalized block
+#[derive(Default)]
+pub struct Finality {
+    set_id: u64,
+    authorities: AuthorityList,
+    pending: Vec<PendingChange>,
+    finalized: Option<(u64, Hash)>,
+}
+
+impl Finality {
+    pub fn new() -> Finality {
+        Finality::default()
+    }
+
+    // Trusted authority set, light client starts following finality from
+    pub fn init(&mut self, set_id: u64, authorities: AuthorityList) -> Result<(), Error> {
+        if !is_valid(&authorities) {
+            return Err(Error::InvalidAuthoritySet);
+        }
+
+        self.set_id = set_id;
+        self.authorities = authorities;
+        self.pending.clear();
+        Ok(())
+    }
+
+    pub fn finalized(&self) -> Option<(u64, Hash)> {
+        self.finalized
+    }
+
+    // Looks for scheduled authority set change in digest of newly
+    // imported header & keeps track of it, until it gets enacted
+    pub fn note_header(&mut self, header: &Header) {
+        for log in header.logs.iter() {
+            let data = match log {
+                DigestItem::Consensus(id, data) if *id == GRANDPA_ENGINE_ID => data,
+                _ => continue,
+            };
+
+            // only `ScheduledChange` is handled, forced changes require
+            // finality to be stalled, which light client can't judge
+            if data.first() != Some(&1) {
+                continue;
+            }
+
+            // no justification could ever be verified against invalid set,
+            // so it's never enacted
+            if let Ok((authorities, delay)) = <(AuthorityList, u32)>::decode(&mut &data[1..]) {
+                if !is_valid(&authorities) {
+                    continue;
+                }
+
+                self.pending.push(PendingChange {
+                    signalled_in: (header.number, header.hash),
+                    effective: header.number + delay as u64,
+                    authorities,
+                });
+            }
+        }
+    }
+
+    // Verifies justification against current authority set, on success
+    // marks its target as finalized & enacts authority set changes, which
+    // are due by then
+    //
+    // Returns finalized block's number & hash
+    pub fn import_justification(&mut self, encoded: &[u8], chain: &HeaderChain) -> Result<(u64, Hash), Error> {
+        if self.authorities.is_empty() {
+            return Err(Error::NoAuthoritySet);
+        }
+
+        let justification = Justification::decode(encoded).ok_or(Error::Malformed)?;
+        self.verify(&justification)?;
+
+        let target = (justification.commit.target_number as u64, justification.commit.target_hash);
+        self.enact(target.0, chain);
+
+        if self.finalized.map(|(n, _)| n < target.0).unwrap_or(true) {
+            self.finalized = Some(target);
+        }
+
+        Ok(target)
+    }
+
+    fn verify(&self, justification: &Justification) -> Result<(), Error> {
+        let commit = &justification.commit;
+
+        let weights: HashMap<AuthorityId, u64> = self.authorities.iter().cloned().collect();
+        let ancestry: HashMap<Hash, &Header> = justification.votes_ancestries.iter().map(|h| (h.hash, h)).collect();
+
+        let mut voters = HashSet::new();
+        let mut weight = 0u64;
+
+        for signed in commit.precommits.iter() {
+            let w = *weights.get(&signed.id).ok_or(Error::UnknownAuthority)?;
+
+            if !voters.insert(signed.id) {
+                return Err(Error::DuplicateVote);
+            }
+
+            // message signed by authority, i.e. `(Message::Precommit, round, set_id)`
+            let mut payload = vec![1u8];
+            payload.extend((signed.precommit.target_hash, signed.precommit.target_number).encode());
+            payload.extend((justification.round, self.set_id).encode());
+
+            let key = PublicKey::from_bytes(&signed.id).map_err(|_| Error::UnknownAuthority)?;
+            let signature = Signature::try_from(&signed.signature[..]).map_err(|_| Error::BadSignature)?;
+            key.verify(&payload, &signature).map_err(|_| Error::BadSignature)?;
+
+            if !descends(&ancestry, &signed.precommit, commit) {
+                return Err(Error::NotDescendant);
+            }
+
+            weight = weight.saturating_add(w);
+        }
+
+        // weights come from untrusted authority list, so are summed up
+        // without overflowing, total is non-zero, as set is valid
+        let total = self.authorities.iter().fold(0u64, |acc, (_, w)| acc.saturating_add(*w));
+        let threshold = total - (total - 1) / 3;
+
+        if weight < threshold {
+            return Err(Error::InsufficientWeight);
+        }
+
+        Ok(())
+    }
+
+    // Enacts pending changes, which are effective at or before finalized
+    // block, given they were signalled on canonical chain
+    fn enact(&mut self, finalized: u64, chain: &HeaderChain) {
+        self.pending.sort_by_key(|c| c.effective);
+
+        while let Some(change) = self.pending.first() {
+            if change.effective > finalized {
+                break;
+            }
+
+            let change = self.pending.remove(0);
+            let (number, hash) = change.signalled_in;
+            if chain.is_canonical(number, &hash) == Some(false) {
+                continue;
+            }
+
+            self.set_id += 1;
+            self.authorities = change.authorities;
+        }
+    }
+}
+
+// Same as GRANDPA voter set, authority set must be non-empty & each of its
+// authorities must carry some weight
+fn is_valid(authorities: &AuthorityList) -> bool {
+    !authorities.is_empty() && authorities.iter().all(|(_, w)| *w > 0)
+}
+
+// Checks whether precommit target is commit target itself or one of its
+// descendants, using headers supplied along with justification
+fn descends(ancestry: &HashMap<Hash, &Header>, precommit: &Precommit, commit: &Commit) -> bool {
+    let mut cursor = precommit.target_hash;
+    let mut number = precommit.target_number;
+
+    loop {
+        if cursor == commit.target_hash {
+            return true;
+        }
+
+        if number <= commit.target_number {
+            return false;
+        }
+
+        match ancestry.get(&cursor) {
+            Some(h) => {
+                cursor = h.parent_hash;
+                number -= 1;
+            }
+            None => return false,
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+
+use codec::{Decode, Encode};
+use dusk_plonk::fft::EvaluationDomain;
+use dusk_plonk::prelude::BlsScalar;
+
+use crate::chain::Hash;
+use crate::{params, row};
+
+// Cell of extended data matrix, along with its 80 bytes opening i.e. 48 bytes
+// witness followed by 32 bytes evaluation, against commitment of its row
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct CellOpening {
+    pub row: u16,
+    pub opening: Vec<u8>,
+}
+
+// Proof that extension data of some column of block is incorrectly encoded
+//
+// Each column of extended matrix must be evaluations of polynomial of degree
+// < `total_rows`, over domain of size `total_rows * 2`, so `total_rows` cells
+// fix whole column. Proof carries that many cells, plus one more, whose
+// committed evaluation disagrees with what those cells interpolate to
+//
+// All openings are against row commitments, sliced out of header commitment
+// at respective row index, so anyone with header can verify it
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct BadEncodingProof {
+    pub block: Hash,
+    pub total_rows: u16,
+    pub total_cols: u16,
+    pub col: u16,
+    // first `total_rows` cells interpolate column, last one contradicts it
+    pub cells: Vec<CellOpening>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    // proof, cells or commitment couldn't be parsed
+    Malformed(String),
+    // some cell's opening doesn't verify against its row commitment
+    InvalidOpening { row: u16 },
+    // less than `total_rows + 1` distinct cells of column are known
+    InsufficientCells { known: usize, required: usize },
+    // cells lie on low degree polynomial, nothing is wrong with encoding
+    Consistent,
+}
+
+// Evaluates polynomial, passing through given points, at `x`, using
+// Lagrange interpolation
+pub fn interpolate_at(points: &[(BlsScalar, BlsScalar)], x: BlsScalar) -> BlsScalar {
+    let mut acc = BlsScalar::zero();
+
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut num = BlsScalar::one();
+        let mut den = BlsScalar::one();
+
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                num *= x - xj;
+                den *= xi - xj;
+            }
+        }
+
+        // points are distinct domain elements, so denominator is never zero
+        acc += yi * num * den.invert().unwrap();
+    }
+
+    acc
+}
+
+// Domain points of column cells & evaluations carried by their openings
+fn points(domain: &[BlsScalar], cells: &[CellOpening]) -> Result<Vec<(BlsScalar, BlsScalar)>, Error> {
+    cells
+        .iter()
+        .map(|cell| {
+            let x = *domain
+                .get(cell.row as usize)
+                .ok_or_else(|| Error::Malformed(format!("row {} out of {} rows", cell.row, domain.len())))?;
+            let y = row::parse_scalars(cell.opening.get(48..).unwrap_or(&[])).map_err(Error::Malformed)?;
+            if y.len() != 1 {
+                return Err(Error::Malformed(format!("opening of row {} with incorrect length", cell.row)));
+            }
+
+            Ok((x, y[0]))
+        })
+        .collect()
+}
+
+// Column evaluation domain of `total_rows * 2` elements
+fn column_domain(total_rows: usize) -> Result<Vec<BlsScalar>, Error> {
+    let extended_rows = total_rows * 2;
+    let domain = EvaluationDomain::new(extended_rows).map_err(|e| Error::Malformed(format!("{:?}", e)))?;
+    if extended_rows == 0 || domain.size() != extended_rows {
+        return Err(Error::Malformed(format!("{} rows is not a power of 2", total_rows)));
+    }
+
+    Ok(domain.elements().collect())
+}
+
+// Header commitment carries one 48 bytes commitment per extended row
+fn check_commitment(commitment: &[u8], total_rows: usize) -> Result<(), Error> {
+    if commitment.len() != total_rows * 2 * 48 {
+        return Err(Error::Malformed(format!(
+            "commitment of length {}, expected {} for {} rows",
+            commitment.len(),
+            total_rows * 2 * 48,
+            total_rows
+        )));
+    }
+
+    Ok(())
+}
+
+// Checks opening of cell against commitment of its row
+fn check_opening(commitment: &[u8], cell: &CellOpening, col: usize, total_cols: usize) -> Result<(), Error> {
+    let start = cell.row as usize * 48;
+    let row_commitment = commitment
+        .get(start..start + 48)
+        .ok_or_else(|| Error::Malformed(format!("no commitment for row {}", cell.row)))?;
+
+    match row::verify_opening(row_commitment, &cell.opening, col, total_cols) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(Error::InvalidOpening { row: cell.row }),
+        Err(e) => Err(Error::Malformed(e)),
+    }
+}
+
+// Collects verified cells of one column, from which bad encoding
+// proof is built, once contradicting cell is found
+pub struct BadEncodingProofBuilder {
+    block: Hash,
+    total_rows: usize,
+    total_cols: usize,
+    col: usize,
+    cells: BTreeMap<u16, Vec<u8>>,
+}
+
+impl BadEncodingProofBuilder {
+    pub fn new(block: Hash, total_rows: usize, total_cols: usize, col: usize) -> BadEncodingProofBuilder {
+        BadEncodingProofBuilder {
+            block,
+            total_rows,
+            total_cols,
+            col,
+            cells: BTreeMap::new(),
+        }
+    }
+
+    // Adds cell of column, with its 80 bytes opening, same cell
+    // added again replaces earlier one
+    pub fn cell(mut self, row: u16, opening: Vec<u8>) -> Self {
+        self.cells.insert(row, opening);
+        self
+    }
+
+    // Builds proof, given header commitment, picking `total_rows` cells for
+    // interpolating column & first other cell contradicting it
+    //
+    // Openings of all picked cells are checked, so that built proof always
+    // verifies, unless column is actually correctly encoded
+    pub fn build(self, commitment: &[u8]) -> Result<BadEncodingProof, Error> {
+        params::require_dusk("fraud proofs are").map_err(Error::Malformed)?;
+
+        if self.total_rows > u16::MAX as usize || self.total_cols > u16::MAX as usize || self.col >= self.total_cols {
+            return Err(Error::Malformed(format!(
+                "column {} out of {} x {} matrix",
+                self.col, self.total_rows, self.total_cols
+            )));
+        }
+
+        check_commitment(commitment, self.total_rows)?;
+
+        let required = self.total_rows + 1;
+        if self.cells.len() < required {
+            return Err(Error::InsufficientCells {
+                known: self.cells.len(),
+                required,
+            });
+        }
+
+        let domain = column_domain(self.total_rows)?;
+        let cells: Vec<CellOpening> = self
+            .cells
+            .into_iter()
+            .map(|(row, opening)| CellOpening { row, opening })
+            .collect();
+
+        let (basis, rest) = cells.split_at(self.total_rows);
+        for cell in basis {
+            check_opening(commitment, cell, self.col, self.total_cols)?;
+        }
+        let basis_points = points(&domain, basis)?;
+
+        for (cell, (x, y)) in rest.iter().zip(points(&domain, rest)?) {
+            if interpolate_at(&basis_points, x) == y {
+                continue;
+            }
+
+            check_opening(commitment, cell, self.col, self.total_cols)?;
+
+            let mut cells = basis.to_vec();
+            cells.push(cell.clone());
+
+            return Ok(BadEncodingProof {
+                block: self.block,
+                total_rows: self.total_rows as u16,
+                total_cols: self.total_cols as u16,
+                col: self.col as u16,
+                cells,
+            });
+        }
+
+        Err(Error::Consistent)
+    }
+}
+
+impl BadEncodingProof {
+    // Verifies proof against header of block it's about, given dimensions of
+    // data matrix & commitment carried in header, `Ok` means column is proven
+    // to be incorrectly encoded
+    //
+    // Dimensions claimed by proof must match header ones, otherwise cells of
+    // correctly encoded block could be interpolated over smaller domain, which
+    // they don't lie on. Caller must make sure header is of `self.block`
+    pub fn verify(&self, total_rows: usize, total_cols: usize, commitment: &[u8]) -> Result<(), Error> {
+        params::require_dusk("fraud proofs are").map_err(Error::Malformed)?;
+
+        if (self.total_rows as usize, self.total_cols as usize) != (total_rows, total_cols) {
+            return Err(Error::Malformed(format!(
+                "proof is of {} x {} matrix, header has {} x {}",
+                self.total_rows, self.total_cols, total_rows, total_cols
+            )));
+        }
+        check_commitment(commitment, total_rows)?;
+
+        let col = self.col as usize;
+
+        if self.cells.len() != total_rows + 1 {
+            return Err(Error::Malformed(format!(
+                "proof carries {} cells, expected {}",
+                self.cells.len(),
+                total_rows + 1
+            )));
+        }
+
+        if col >= total_cols {
+            return Err(Error::Malformed(format!("column {} out of {} columns", col, total_cols)));
+        }
+
+        let mut rows: Vec<u16> = self.cells.iter().map(|cell| cell.row).collect();
+        rows.sort_unstable();
+        rows.dedup();
+        if rows.len() != self.cells.len() {
+            return Err(Error::Malformed("proof carries same cell more than once".to_string()));
+        }
+
+        let domain = column_domain(total_rows)?;
+        let points = points(&domain, &self.cells)?;
+
+        for cell in self.cells.iter() {
+            check_opening(commitment, cell, col, total_cols)?;
+        }
+
+        let (x, y) = points[total_rows];
+        if interpolate_at(&points[..total_rows], x) == y {
+            return Err(Error::Consistent);
+        }
+
+        Ok(())
+    }
+}
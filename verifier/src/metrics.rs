@@ -0,0 +1,100 @@
+use std::thread;
+
+use prometheus::{Encoder, Histogram, IntCounter, IntGauge, TextEncoder};
+use tiny_http::{Header, Response, Server};
+
+lazy_static! {
+    pub static ref CELLS_VERIFIED: IntCounter = register_int_counter!(
+        "light_cells_verified_total",
+        "Number of cells whose proof got successfully verified"
+    )
+    .unwrap();
+    pub static ref CELLS_FAILED: IntCounter = register_int_counter!(
+        "light_cells_failed_total",
+        "Number of cells whose proof failed to verify"
+    )
+    .unwrap();
+    pub static ref BLOCK_VERIFICATION_TIME: Histogram = register_histogram!(
+        "light_block_verification_seconds",
+        "Time taken for verifying all sampled cells of one block"
+    )
+    .unwrap();
+    pub static ref PROOF_FETCH_TIME: Histogram = register_histogram!(
+        "light_proof_fetch_seconds",
+        "Time taken for fetching proofs of sampled cells from full node"
+    )
+    .unwrap();
+    pub static ref PAIRING_TIME: Histogram = register_histogram!(
+        "light_pairing_seconds",
+        "Time taken for pairing check of one cell proof",
+        vec![0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25]
+    )
+    .unwrap();
+    pub static ref BACKLOG_SIZE: IntGauge = register_int_gauge!(
+        "light_backlog_size",
+        "Number of blocks waiting to be verified"
+    )
+    .unwrap();
+    pub static ref LATEST_HEAD: IntGauge = register_int_gauge!(
+        "light_latest_head",
+        "Latest chain head imported into header chain"
+    )
+    .unwrap();
+    pub static ref LATEST_VERIFIED: IntGauge = register_int_gauge!(
+        "light_latest_verified",
+        "Latest block, for which target confidence was reached"
+    )
+    .unwrap();
+    pub static ref CACHE_HITS: IntCounter = register_int_counter!(
+        "light_proof_cache_hits_total",
+        "Number of cell proofs served from cache"
+    )
+    .unwrap();
+    pub static ref CACHE_MISSES: IntCounter = register_int_counter!(
+        "light_proof_cache_misses_total",
+        "Number of cell proofs not found in cache"
+    )
+    .unwrap();
+    pub static ref CACHE_ENTRIES: IntGauge = register_int_gauge!(
+        "light_proof_cache_entries",
+        "Number of cell proofs held in cache"
+    )
+    .unwrap();
+}
+
+// Renders all registered metrics in prometheus text format
+fn render() -> (Vec<u8>, String) {
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+
+    encoder
+        .encode(&prometheus::gather(), &mut buffer)
+        .expect("metrics encoding to succeed");
+
+    (buffer, encoder.format_type().to_string())
+}
+
+// Starts HTTP server on separate thread, exposing metrics at `/metrics`
+pub fn serve(port: u16) -> Result<(), String> {
+    let server = Server::http(("0.0.0.0", port)).map_err(|e| e.to_string())?;
+
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let response = if request.url() == "/metrics" {
+                let (body, format) = render();
+                Response::from_data(body).with_header(
+                    Header::from_bytes(&b"Content-Type"[..], format.as_bytes())
+                        .expect("valid content type header"),
+                )
+            } else {
+                Response::from_string("not found").with_status_code(404)
+            };
+
+            if let Err(e) = request.respond(response) {
+                println!("❌  Failed to respond to metrics request : {}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
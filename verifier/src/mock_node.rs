@@ -0,0 +1,500 @@
+// Mock full node, producing blocks with random data matrices & serving them
+// over WebSocket JSON-RPC, same as full node does, so that light client can
+// be tested end to end, without running chain
+//
+// Only these methods are served
+//
+// - `chain_subscribeNewHeads` / `chain_unsubscribeNewHeads`
+// - `chain_getBlockHash`
+// - `chain_getHeader`
+// - `chain_getBlock`
+// - `kate_queryProof`
+// - `kate_queryMultiProof`
+//
+// @note Commitments & proofs are real, built using test-only prover, node
+// can also be asked to misbehave, for checking light client catches it
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use blake2_rfc::blake2b::blake2b;
+use codec::{Compact, Encode};
+use serde_json::{json, Value};
+use tungstenite::{accept, Message, WebSocket};
+
+use crate::chain::Hash;
+use crate::testing::Fixture;
+
+// Socket is polled for requests this often, in between pushing new heads
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+// How mock node deviates from honest full node
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Misbehaviour {
+    Honest,
+    // proofs of this fraction of cells are never served
+    WithholdCells(f64),
+    // proofs of this fraction of cells carry wrong evaluation
+    BadProofs(f64),
+    // header advertises twice as many rows as data matrix has, so that
+    // commitment is half as long as it should be
+    WrongDimensions,
+}
+
+#[derive(Clone, Debug)]
+pub struct MockConfig {
+    // dimensions of original data matrix, each block's matrix is extended
+    // to twice as many rows
+    pub rows: usize,
+    pub cols: usize,
+    // blocks are produced only when asked for, if not set
+    pub block_time: Option<Duration>,
+    // data matrix of each block is derived from it & block number
+    pub seed: u64,
+    pub misbehaviour: Misbehaviour,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        MockConfig {
+            rows: 4,
+            cols: 8,
+            block_time: None,
+            seed: 0,
+            misbehaviour: Misbehaviour::Honest,
+        }
+    }
+}
+
+struct Block {
+    hash: Hash,
+    parent_hash: Hash,
+    number: u32,
+    fixture: Fixture,
+}
+
+impl Block {
+    // Rows of original data matrix, as advertised in header, which may not
+    // be real ones
+    fn advertised_rows(&self, misbehaviour: Misbehaviour) -> usize {
+        match misbehaviour {
+            Misbehaviour::WrongDimensions => self.fixture.total_rows * 2,
+            _ => self.fixture.total_rows,
+        }
+    }
+
+    fn extrinsics_root(&self) -> Hash {
+        blake2b(32, &[], &self.fixture.commitment).as_bytes().try_into().unwrap()
+    }
+
+    // SCALE encoded header, same as decoded by header chain
+    fn encode(&self, misbehaviour: Misbehaviour) -> Vec<u8> {
+        let mut encoded = self.parent_hash.encode();
+        Compact(self.number).encode_to(&mut encoded);
+        [0u8; 32].encode_to(&mut encoded);
+        self.extrinsics_root().encode_to(&mut encoded);
+        self.fixture.commitment.encode_to(&mut encoded);
+        (self.advertised_rows(misbehaviour) as u16).encode_to(&mut encoded);
+        (self.fixture.total_cols as u16).encode_to(&mut encoded);
+        // no digest logs
+        Compact(0u32).encode_to(&mut encoded);
+        // no application data lookup
+        Compact(0u32).encode_to(&mut encoded);
+        Compact(0u32).encode_to(&mut encoded);
+
+        encoded
+    }
+
+    fn header_json(&self, misbehaviour: Misbehaviour) -> Value {
+        json!({
+            "parentHash": hex_of(&self.parent_hash),
+            "number": format!("0x{:x}", self.number),
+            "stateRoot": hex_of(&[0u8; 32]),
+            "extrinsicsRoot": {
+                "hash": hex_of(&self.extrinsics_root()),
+                "commitment": hex_of(&self.fixture.commitment),
+                "rows": self.advertised_rows(misbehaviour),
+                "cols": self.fixture.total_cols,
+            },
+            "digest": { "logs": [] },
+            "appDataLookup": { "size": 0, "index": [] },
+        })
+    }
+}
+
+fn hex_of(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+// Whether cell is one of given fraction of cells of block, picked
+// deterministically, so that repeated queries get same answer
+fn picked(number: u32, row: usize, col: usize, fraction: f64) -> bool {
+    let mut input = number.to_le_bytes().to_vec();
+    input.extend_from_slice(&(row as u32).to_le_bytes());
+    input.extend_from_slice(&(col as u32).to_le_bytes());
+
+    let hash = blake2b(8, &[], &input);
+    let v = u64::from_le_bytes(hash.as_bytes().try_into().unwrap());
+    (v as f64 / u64::MAX as f64) < fraction
+}
+
+struct Chain {
+    config: MockConfig,
+    blocks: Vec<Block>,
+    by_hash: HashMap<Hash, usize>,
+    // new head subscribers, keyed by subscription id
+    subscribers: HashMap<u64, Sender<String>>,
+    next_subscription: u64,
+}
+
+impl Chain {
+    fn new(config: MockConfig) -> Chain {
+        let mut chain = Chain {
+            config,
+            blocks: vec![],
+            by_hash: HashMap::new(),
+            subscribers: HashMap::new(),
+            next_subscription: 1,
+        };
+
+        // genesis
+        chain.produce();
+        chain
+    }
+
+    fn produce(&mut self) -> Hash {
+        let number = self.blocks.len() as u32;
+        let parent_hash = self.blocks.last().map(|b| b.hash).unwrap_or([0u8; 32]);
+        let fixture = Fixture::random(self.config.rows, self.config.cols, self.config.seed ^ number as u64);
+
+        let mut block = Block {
+            hash: [0u8; 32],
+            parent_hash,
+            number,
+            fixture,
+        };
+        block.hash = blake2b(32, &[], &block.encode(self.config.misbehaviour))
+            .as_bytes()
+            .try_into()
+            .unwrap();
+
+        let hash = block.hash;
+        let notification = block.header_json(self.config.misbehaviour);
+
+        self.by_hash.insert(hash, self.blocks.len());
+        self.blocks.push(block);
+
+        // subscribers, whose connection is gone, are dropped
+        self.subscribers.retain(|id, tx| {
+            tx.send(
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "chain_newHead",
+                    "params": { "subscription": id, "result": notification },
+                })
+                .to_string(),
+            )
+            .is_ok()
+        });
+
+        hash
+    }
+
+    // Block with given hash, latest one if not given
+    fn block(&self, hash: Option<&Value>) -> Result<Option<&Block>, String> {
+        match hash {
+            None | Some(Value::Null) => Ok(self.blocks.last()),
+            Some(Value::String(h)) => {
+                let hash: Hash = hex::decode(h.trim_start_matches("0x"))
+                    .ok()
+                    .and_then(|h| h.try_into().ok())
+                    .ok_or_else(|| format!("invalid block hash {}", h))?;
+                Ok(self.by_hash.get(&hash).map(|i| &self.blocks[*i]))
+            }
+            Some(v) => Err(format!("invalid block hash {}", v)),
+        }
+    }
+
+    // Proofs of requested cells, concatenated, as per misbehaviour
+    fn query_proof(&self, number: u64, cells: &[Value]) -> Result<Vec<u8>, String> {
+        let block = self
+            .blocks
+            .get(number as usize)
+            .ok_or_else(|| format!("unknown block {}", number))?;
+        let fixture = &block.fixture;
+
+        let mut proof = Vec::with_capacity(cells.len() * 80);
+        for cell in cells {
+            let row = cell["row"].as_u64().ok_or("cell without row")? as usize;
+            let col = cell["col"].as_u64().ok_or("cell without col")? as usize;
+            if row >= fixture.total_rows * 2 || col >= fixture.total_cols {
+                return Err(format!("cell ({}, {}) out of bounds", row, col));
+            }
+
+            let mut p = fixture.proof(row, col);
+            match self.config.misbehaviour {
+                Misbehaviour::WithholdCells(f) if picked(block.number, row, col, f) => {
+                    return Err(format!("cell ({}, {}) not available", row, col));
+                }
+                Misbehaviour::BadProofs(f) if picked(block.number, row, col, f) => {
+                    // evaluation off by one, witness left as is
+                    p[79] ^= 1;
+                }
+                _ => {}
+            }
+
+            proof.extend_from_slice(&p);
+        }
+
+        Ok(proof)
+    }
+
+    // Multiproof of cells of one row, misbehaving same way as for single
+    // cell proofs, if any of asked cells is picked
+    fn query_multiproof(&self, number: u64, row: u64, cols: &[Value]) -> Result<Vec<u8>, String> {
+        let block = self
+            .blocks
+            .get(number as usize)
+            .ok_or_else(|| format!("unknown block {}", number))?;
+        let fixture = &block.fixture;
+
+        let row = row as usize;
+        let cols = cols
+            .iter()
+            .map(|col| col.as_u64().map(|col| col as usize).ok_or("column is not a number"))
+            .collect::<Result<Vec<usize>, _>>()?;
+        if cols.is_empty() {
+            return Err("no columns asked".to_string());
+        }
+        if row >= fixture.total_rows * 2 || cols.iter().any(|&col| col >= fixture.total_cols) {
+            return Err(format!("cells of row {} out of bounds", row));
+        }
+
+        let mut proof = fixture.multiproof(row, &cols);
+        for (i, &col) in cols.iter().enumerate() {
+            match self.config.misbehaviour {
+                Misbehaviour::WithholdCells(f) if picked(block.number, row, col, f) => {
+                    return Err(format!("cell ({}, {}) not available", row, col));
+                }
+                Misbehaviour::BadProofs(f) if picked(block.number, row, col, f) => {
+                    // evaluation off by one, witnesses left as is
+                    proof[96 + i * 32 + 31] ^= 1;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(proof)
+    }
+}
+
+// Parses block number, passed either as number or hex string
+fn number_of(v: &Value) -> Option<u64> {
+    match v {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => u64::from_str_radix(s.trim_start_matches("0x"), 16).ok(),
+        _ => None,
+    }
+}
+
+// Serves one JSON-RPC request, `subscribe` is invoked when connection
+// subscribes to new heads, returning subscription id
+fn handle(chain: &Mutex<Chain>, request: &Value, subscribe: &mut dyn FnMut(&mut Chain) -> u64) -> Result<Value, String> {
+    let params = request["params"].as_array().cloned().unwrap_or_default();
+    let mut chain = chain.lock().unwrap();
+    let misbehaviour = chain.config.misbehaviour;
+
+    match request["method"].as_str().unwrap_or_default() {
+        "chain_subscribeNewHeads" => Ok(json!(subscribe(&mut *chain))),
+        "chain_unsubscribeNewHeads" => {
+            let id = params.get(0).and_then(|v| v.as_u64()).ok_or("missing subscription id")?;
+            Ok(json!(chain.subscribers.remove(&id).is_some()))
+        }
+        "chain_getBlockHash" => {
+            let block = match params.get(0) {
+                None | Some(Value::Null) => chain.blocks.last(),
+                Some(v) => {
+                    let number = number_of(v).ok_or_else(|| format!("invalid block number {}", v))?;
+                    chain.blocks.get(number as usize)
+                }
+            };
+            Ok(block.map(|b| json!(hex_of(&b.hash))).unwrap_or(Value::Null))
+        }
+        "chain_getHeader" => Ok(chain
+            .block(params.get(0))?
+            .map(|b| b.header_json(misbehaviour))
+            .unwrap_or(Value::Null)),
+        "chain_getBlock" => Ok(chain
+            .block(params.get(0))?
+            .map(|b| {
+                json!({
+                    "block": { "header": b.header_json(misbehaviour), "extrinsics": [] },
+                    "justifications": null,
+                })
+            })
+            .unwrap_or(Value::Null)),
+        "kate_queryProof" => {
+            let number = params.get(0).and_then(number_of).ok_or("missing block number")?;
+            let cells = params.get(1).and_then(|v| v.as_array()).ok_or("missing cells")?;
+            Ok(json!(chain.query_proof(number, cells)?))
+        }
+        "kate_queryMultiProof" => {
+            let number = params.get(0).and_then(number_of).ok_or("missing block number")?;
+            let row = params.get(1).and_then(|v| v.as_u64()).ok_or("missing row")?;
+            let cols = params.get(2).and_then(|v| v.as_array()).ok_or("missing columns")?;
+            Ok(json!(chain.query_multiproof(number, row, cols)?))
+        }
+        method => Err(format!("method {} not found", method)),
+    }
+}
+
+// Serves requests of one connection, pushing new heads in between
+fn serve(chain: Arc<Mutex<Chain>>, mut socket: WebSocket<TcpStream>, stopped: Arc<AtomicBool>) {
+    let (tx, rx): (Sender<String>, Receiver<String>) = channel();
+
+    while !stopped.load(Ordering::Relaxed) {
+        match socket.read_message() {
+            Ok(Message::Text(text)) => {
+                let request: Value = match serde_json::from_str(&text) {
+                    Ok(request) => request,
+                    Err(_) => continue,
+                };
+
+                let mut subscribe = |chain: &mut Chain| {
+                    let id = chain.next_subscription;
+                    chain.next_subscription += 1;
+                    chain.subscribers.insert(id, tx.clone());
+                    id
+                };
+
+                let response = match handle(&chain, &request, &mut subscribe) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+                    Err(e) => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": { "code": -32000, "message": e },
+                    }),
+                };
+
+                if socket.write_message(Message::Text(response.to_string())).is_err() {
+                    return;
+                }
+            }
+            Ok(Message::Close(_)) => return,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e)) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {}
+            Err(_) => return,
+        }
+
+        while let Ok(notification) = rx.try_recv() {
+            if socket.write_message(Message::Text(notification)).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Handle to running mock node, which is stopped when dropped
+pub struct MockNode {
+    pub addr: SocketAddr,
+    chain: Arc<Mutex<Chain>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockNode {
+    // Starts serving at given address, pass port 0 for picking any free port
+    pub fn start(addr: SocketAddr, config: MockConfig) -> Result<MockNode, String> {
+        let listener = TcpListener::bind(addr).map_err(|e| format!("failed to bind {} : {}", addr, e))?;
+        let addr = listener.local_addr().map_err(|e| e.to_string())?;
+        listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+        let block_time = config.block_time;
+        let chain = Arc::new(Mutex::new(Chain::new(config)));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        {
+            let chain = chain.clone();
+            let stopped = stopped.clone();
+
+            thread::spawn(move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    let stream = match listener.accept() {
+                        Ok((stream, _)) => stream,
+                        Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                            thread::sleep(POLL_INTERVAL);
+                            continue;
+                        }
+                        Err(_) => return,
+                    };
+
+                    if stream.set_nonblocking(false).is_err() {
+                        continue;
+                    }
+
+                    let socket = match accept(stream) {
+                        Ok(socket) => socket,
+                        Err(_) => continue,
+                    };
+                    if socket.get_ref().set_read_timeout(Some(POLL_INTERVAL)).is_err() {
+                        continue;
+                    }
+
+                    let chain = chain.clone();
+                    let stopped = stopped.clone();
+                    thread::spawn(move || serve(chain, socket, stopped));
+                }
+            });
+        }
+
+        if let Some(block_time) = block_time {
+            let chain = chain.clone();
+            let stopped = stopped.clone();
+
+            thread::spawn(move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    thread::sleep(block_time);
+                    chain.lock().unwrap().produce();
+                }
+            });
+        }
+
+        Ok(MockNode { addr, chain, stopped })
+    }
+
+    // WebSocket URI light client can connect to
+    pub fn uri(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    // Produces next block, notifying subscribers, returns its hash
+    pub fn produce_block(&self) -> Hash {
+        self.chain.lock().unwrap().produce()
+    }
+
+    // Fixture, block with given number was built from, for checking
+    // what light client received
+    pub fn with_block<T>(&self, number: u32, f: impl FnOnce(&Hash, &Fixture) -> T) -> Option<T> {
+        let chain = self.chain.lock().unwrap();
+        chain.blocks.get(number as usize).map(|b| f(&b.hash, &b.fixture))
+    }
+
+    // SCALE encoded header of block, as header chain expects it
+    pub fn encoded_header(&self, number: u32) -> Option<Vec<u8>> {
+        let chain = self.chain.lock().unwrap();
+        let misbehaviour = chain.config.misbehaviour;
+        chain.blocks.get(number as usize).map(|b| b.encode(misbehaviour))
+    }
+}
+
+impl Drop for MockNode {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,113 @@
+// Single opening proving evaluations of several cells of one row, so that
+// a range of row can be fetched & verified at once, instead of one 80 bytes
+// proof per cell
+//
+// Given row polynomial p, committed as C, set S of domain points, cells are
+// opened at & their evaluations, I is polynomial of degree < |S| passing
+// through all of them & Z is vanishing polynomial of S, so that
+//
+//      p(x) - I(x) = q(x) · Z(x)
+//
+// holds, only when all evaluations are correct. Opening key carries only
+// [τ]₂, so instead of checking that with [Z(τ)]₂, prover commits to q, as
+// W₁ & opens combined polynomial
+//
+//      L(x) = p(x) - I(z) - Z(z) · q(x)
+//
+// at Fiat-Shamir challenge z, where it must vanish, with witness W₂. Verifier
+// builds [L(τ)]₁ = C - I(z) · G - Z(z) · W₁ & checks it's an opening of 0 at z
+//
+// Multiproof is laid out as 48 bytes W₁, 48 bytes W₂ & 32 bytes evaluation of
+// each cell, in order of requested columns
+
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use blake2_rfc::blake2b::blake2b;
+use dusk_plonk::bls12_381::{G1Affine, G1Projective};
+use dusk_plonk::commitment_scheme::kzg10;
+use dusk_plonk::fft::EvaluationDomain;
+use dusk_plonk::prelude::BlsScalar;
+
+use crate::{fraud, params, row};
+
+// W₁ & W₂, preceding evaluations
+pub const WITNESSES_SIZE: usize = 96;
+
+// Point, combined polynomial is opened at, bound to everything prover
+// committed to, before opening
+pub fn challenge(commitment: &[u8], cols: &[usize], evals: &[u8], quotient: &[u8]) -> BlsScalar {
+    let mut input = commitment.to_vec();
+    for col in cols {
+        input.extend_from_slice(&(*col as u32).to_le_bytes());
+    }
+    input.extend_from_slice(evals);
+    input.extend_from_slice(quotient);
+
+    let wide: [u8; 64] = blake2b(64, &[], &input).as_bytes().try_into().unwrap();
+    BlsScalar::from_bytes_wide(&wide)
+}
+
+// Evaluates vanishing polynomial of given points, at `z`
+pub fn vanishing_at(points: &[BlsScalar], z: BlsScalar) -> BlsScalar {
+    points.iter().fold(BlsScalar::one(), |acc, point| acc * (z - point))
+}
+
+fn parse_witness(bytes: &[u8], name: &str) -> Result<G1Affine, String> {
+    row::parse_commitment(bytes).map_err(|_| format!("{} witness is not a valid point", name))
+}
+
+// Verifies multiproof of cells at given columns of one row, against 48 bytes
+// commitment of that row, failing on malformed input
+pub fn verify(commitment: &[u8], proof: &[u8], cols: &[usize], total_cols: usize) -> Result<bool, String> {
+    params::require_dusk("multiproofs are")?;
+
+    if cols.is_empty() {
+        return Err("no cells to verify".to_string());
+    }
+
+    if proof.len() != WITNESSES_SIZE + cols.len() * 32 {
+        return Err(format!(
+            "multiproof of length {}, expected {}",
+            proof.len(),
+            WITNESSES_SIZE + cols.len() * 32
+        ));
+    }
+
+    if total_cols == 0 || total_cols > u16::MAX as usize {
+        return Err(format!("invalid number of columns {}", total_cols));
+    }
+
+    let row_eval_domain = EvaluationDomain::new(total_cols).map_err(|e| format!("{:?}", e))?;
+    if let Some(col) = cols.iter().find(|&&col| col >= row_eval_domain.size()) {
+        return Err(format!("column {} out of {} columns", col, total_cols));
+    }
+
+    if cols.iter().collect::<HashSet<_>>().len() != cols.len() {
+        return Err("same column asked more than once".to_string());
+    }
+
+    let (_, opening_key) = params::PUBLIC_PARAMS.trim(total_cols).map_err(|e| format!("{:?}", e))?;
+
+    let commitment_point = row::parse_commitment(commitment)?;
+    let quotient = parse_witness(&proof[..48], "quotient")?;
+    let witness = parse_witness(&proof[48..WITNESSES_SIZE], "opening")?;
+    let evals = row::parse_scalars(&proof[WITNESSES_SIZE..])?;
+
+    let elements: Vec<BlsScalar> = row_eval_domain.elements().collect();
+    let xs: Vec<BlsScalar> = cols.iter().map(|&col| elements[col]).collect();
+    let points: Vec<(BlsScalar, BlsScalar)> = xs.iter().cloned().zip(evals.into_iter()).collect();
+
+    let z = challenge(commitment, cols, &proof[WITNESSES_SIZE..], &proof[..48]);
+    let combined: G1Projective = G1Projective::from(commitment_point)
+        - G1Affine::generator() * fraud::interpolate_at(&points, z)
+        - quotient * vanishing_at(&xs, z);
+
+    let proof = kzg10::Proof {
+        commitment_to_witness: kzg10::Commitment::from_affine(witness),
+        evaluated_point: BlsScalar::zero(),
+        commitment_to_polynomial: kzg10::Commitment::from_affine(G1Affine::from(combined)),
+    };
+
+    Ok(opening_key.check(z, proof))
+}
@@ -0,0 +1,314 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_std::{future, task};
+use blake2_rfc::blake2b::blake2b;
+use codec::{Decode, Encode};
+use futures::channel::{mpsc, oneshot};
+use futures::{future::join_all, select, StreamExt};
+use libp2p::gossipsub::{
+    Gossipsub, GossipsubConfigBuilder, GossipsubEvent, GossipsubMessage, IdentTopic, MessageAuthenticity, MessageId,
+    ValidationMode,
+};
+use libp2p::kad::record::store::{MemoryStore, MemoryStoreConfig};
+use libp2p::kad::record::Key;
+use libp2p::kad::{GetRecordOk, Kademlia, KademliaConfig, KademliaEvent, QueryId, QueryResult, Quorum, Record};
+use libp2p::multiaddr::Protocol;
+use libp2p::swarm::SwarmEvent;
+use libp2p::{development_transport, identity, Multiaddr, NetworkBehaviour, PeerId, Swarm};
+
+use crate::chain::Hash;
+use crate::config;
+
+// At max these many cell proofs are held in local record store
+const MAX_RECORDS: usize = 1 << 16;
+
+// Kademlia queries are given up after this
+const QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Topic, light clients announce unavailable blocks on
+const ALERT_TOPIC: &str = "/da-light/unavailable/1";
+
+// Received alerts, which are yet to be picked up, oldest ones are
+// dropped beyond this
+const MAX_PENDING_ALERTS: usize = 1024;
+
+// At max these many failed cells are carried in one alert, same as
+// how many can be verified in one go
+const MAX_ALERT_CELLS: usize = u8::MAX as usize;
+
+// Announcement of block, which some light client couldn't verify enough
+// cells of, carrying commitment it sampled against & cells which failed
+//
+// @note Alerts aren't trusted, receiver must re-sample listed cells itself,
+// before accepting block as unavailable
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct Alert {
+    pub hash: Hash,
+    pub number: u64,
+    pub commitment: Vec<u8>,
+    // row & column of failed cells
+    pub cells: Vec<(u16, u16)>,
+}
+
+impl Alert {
+    // Checks alert is well formed, so that receivers don't
+    // waste time re-sampling garbage
+    pub fn validate(&self) -> Result<(), String> {
+        if self.commitment.is_empty() || self.commitment.len() % 48 != 0 {
+            return Err(format!("commitment of length {} not multiple of 48", self.commitment.len()));
+        }
+
+        if self.cells.is_empty() || self.cells.len() > MAX_ALERT_CELLS {
+            return Err(format!("alert must carry [1, {}] cells, found {}", MAX_ALERT_CELLS, self.cells.len()));
+        }
+
+        let rows = self.commitment.len() / 48;
+        if let Some((row, col)) = self.cells.iter().find(|(row, _)| *row as usize >= rows) {
+            return Err(format!("cell ({}, {}) is out of {} committed rows", row, col, rows));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(NetworkBehaviour)]
+#[behaviour(out_event = "Event", event_process = false)]
+struct Behaviour {
+    kademlia: Kademlia<MemoryStore>,
+    gossipsub: Gossipsub,
+}
+
+enum Event {
+    Kademlia(KademliaEvent),
+    Gossipsub(GossipsubEvent),
+}
+
+impl From<KademliaEvent> for Event {
+    fn from(event: KademliaEvent) -> Self {
+        Event::Kademlia(event)
+    }
+}
+
+impl From<GossipsubEvent> for Event {
+    fn from(event: GossipsubEvent) -> Self {
+        Event::Gossipsub(event)
+    }
+}
+
+enum Command {
+    Put {
+        key: Key,
+        value: Vec<u8>,
+    },
+    Get {
+        key: Key,
+        reply: oneshot::Sender<Option<Vec<u8>>>,
+    },
+    Announce(Alert),
+}
+
+// DHT record key of a cell, i.e. block hash followed by
+// big endian row & column
+pub fn cell_key(hash: &Hash, row: u32, col: u32) -> Key {
+    let mut key = hash.to_vec();
+    key.extend_from_slice(&row.to_be_bytes());
+    key.extend_from_slice(&col.to_be_bytes());
+
+    Key::new(&key)
+}
+
+// Handle to Kademlia DHT node, running on a background task, over which
+// light clients share proofs of cells they've already verified & gossip
+// alerts about unavailable blocks
+pub struct Network {
+    pub peer_id: PeerId,
+    pub listen_addr: Multiaddr,
+    commands: mpsc::UnboundedSender<Command>,
+    alerts: Arc<Mutex<VecDeque<Alert>>>,
+}
+
+impl Network {
+    // Starts listening on given address & joins DHT via bootstrap peers, whose
+    // addresses must end with `/p2p/<peer id>`
+    pub fn start(listen: Multiaddr, bootstrap: &[Multiaddr]) -> Result<Network, String> {
+        let local_key = identity::Keypair::generate_ed25519();
+        let peer_id = PeerId::from(local_key.public());
+        let transport = task::block_on(development_transport(local_key.clone())).map_err(|e| e.to_string())?;
+
+        let store = MemoryStore::with_config(
+            peer_id,
+            MemoryStoreConfig {
+                max_records: MAX_RECORDS,
+                ..Default::default()
+            },
+        );
+        let mut config = KademliaConfig::default();
+        config.set_query_timeout(QUERY_TIMEOUT);
+
+        let mut kademlia = Kademlia::with_config(peer_id, store, config);
+        for addr in bootstrap {
+            let (peer, addr) = split_peer(addr)?;
+            kademlia.add_address(&peer, addr);
+        }
+
+        // same alert sent by many light clients is relayed only once
+        let gossipsub_config = GossipsubConfigBuilder::default()
+            .validation_mode(ValidationMode::Strict)
+            .message_id_fn(|m: &GossipsubMessage| MessageId::from(blake2b(32, &[], &m.data).as_bytes()))
+            .build()
+            .map_err(|e| e.to_string())?;
+        let mut gossipsub = Gossipsub::new(MessageAuthenticity::Signed(local_key), gossipsub_config).map_err(|e| e.to_string())?;
+        gossipsub
+            .subscribe(&IdentTopic::new(ALERT_TOPIC))
+            .map_err(|e| format!("{:?}", e))?;
+
+        let mut swarm = Swarm::new(transport, Behaviour { kademlia, gossipsub }, peer_id);
+        swarm.listen_on(listen).map_err(|e| e.to_string())?;
+
+        if !bootstrap.is_empty() {
+            swarm.behaviour_mut().kademlia.bootstrap().map_err(|e| format!("{:?}", e))?;
+        }
+        for addr in bootstrap {
+            swarm.dial_addr(addr.clone()).map_err(|e| e.to_string())?;
+        }
+
+        let listen_addr = task::block_on(async {
+            loop {
+                if let SwarmEvent::NewListenAddr { address, .. } = swarm.select_next_some().await {
+                    break address;
+                }
+            }
+        });
+
+        let (tx, rx) = mpsc::unbounded();
+        let alerts = Arc::new(Mutex::new(VecDeque::new()));
+        task::spawn(run(swarm, rx, alerts.clone()));
+
+        Ok(Network {
+            peer_id,
+            listen_addr,
+            commands: tx,
+            alerts,
+        })
+    }
+
+    // Address other peers can use for bootstrapping off this node
+    pub fn address(&self) -> Multiaddr {
+        self.listen_addr.clone().with(Protocol::P2p(self.peer_id.into()))
+    }
+
+    // Publishes record in DHT, without waiting for it to be replicated
+    pub fn put(&self, key: Key, value: Vec<u8>) {
+        let _ = self.commands.unbounded_send(Command::Put { key, value });
+    }
+
+    // Gossips alert to all light clients
+    pub fn announce(&self, alert: Alert) {
+        let _ = self.commands.unbounded_send(Command::Announce(alert));
+    }
+
+    // Oldest alert received from other light clients, not yet picked up
+    pub fn next_alert(&self) -> Option<Alert> {
+        self.alerts.lock().unwrap().pop_front()
+    }
+
+    // Looks up all given records in DHT, concurrently, `None` is returned for
+    // records which couldn't be found before timeout
+    pub fn get_many(&self, keys: Vec<Key>, timeout: Duration) -> Vec<Option<Vec<u8>>> {
+        let lookups = keys.into_iter().map(|key| {
+            let (reply, rx) = oneshot::channel();
+            let sent = self.commands.unbounded_send(Command::Get { key, reply }).is_ok();
+
+            async move {
+                if !sent {
+                    return None;
+                }
+
+                future::timeout(timeout, rx).await.ok().and_then(|v| v.ok()).flatten()
+            }
+        });
+
+        task::block_on(join_all(lookups))
+    }
+}
+
+// Splits `/p2p/<peer id>` suffix out of bootstrap address
+fn split_peer(addr: &Multiaddr) -> Result<(PeerId, Multiaddr), String> {
+    let mut addr = addr.clone();
+
+    match addr.pop() {
+        Some(Protocol::P2p(hash)) => {
+            let peer = PeerId::from_multihash(hash).map_err(|_| format!("invalid peer id in {}", addr))?;
+            Ok((peer, addr))
+        }
+        _ => Err(format!("bootstrap address {} must end with /p2p/<peer id>", addr)),
+    }
+}
+
+// Drives swarm, serving commands issued via `Network` handle & queueing
+// well formed alerts received from other light clients
+async fn run(
+    mut swarm: Swarm<Behaviour>,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    alerts: Arc<Mutex<VecDeque<Alert>>>,
+) {
+    let mut pending: HashMap<QueryId, oneshot::Sender<Option<Vec<u8>>>> = HashMap::new();
+
+    loop {
+        select! {
+            command = commands.next() => match command {
+                Some(Command::Put { key, value }) => {
+                    let _ = swarm.behaviour_mut().kademlia.put_record(Record::new(key, value), Quorum::One);
+                }
+                Some(Command::Get { key, reply }) => {
+                    let id = swarm.behaviour_mut().kademlia.get_record(&key, Quorum::One);
+                    pending.insert(id, reply);
+                }
+                Some(Command::Announce(alert)) => {
+                    // fails when no other light client is known yet, nobody to warn
+                    let _ = swarm.behaviour_mut().gossipsub.publish(IdentTopic::new(ALERT_TOPIC), alert.encode());
+                }
+                // handle dropped, nothing more to serve
+                None => return,
+            },
+            event = swarm.select_next_some() => match event {
+                SwarmEvent::Behaviour(Event::Kademlia(KademliaEvent::OutboundQueryCompleted {
+                    id,
+                    result: QueryResult::GetRecord(result),
+                    ..
+                })) => {
+                    if let Some(reply) = pending.remove(&id) {
+                        let value = match result {
+                            Ok(GetRecordOk { records, .. }) => records.into_iter().next().map(|r| r.record.value),
+                            Err(_) => None,
+                        };
+                        let _ = reply.send(value);
+                    }
+                }
+                SwarmEvent::Behaviour(Event::Gossipsub(GossipsubEvent::Message { message, .. })) => {
+                    let alert = Alert::decode(&mut &message.data[..])
+                        .map_err(|e| e.to_string())
+                        .and_then(|alert| alert.validate().map(|_| alert));
+
+                    match alert {
+                        Ok(alert) => {
+                            let mut alerts = alerts.lock().unwrap();
+                            if alerts.len() == MAX_PENDING_ALERTS {
+                                alerts.pop_front();
+                            }
+                            alerts.push_back(alert);
+                        }
+                        Err(e) => {
+                            if config::log_enabled(config::LogLevel::Warn) {
+                                println!("⚠️  Dropping malformed alert from {:?} : {}", message.source, e);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
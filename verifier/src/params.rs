@@ -0,0 +1,738 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use blake2_rfc::blake2b::blake2b;
+use dusk_plonk::commitment_scheme::kzg10::PublicParameters;
+use serde::{Deserialize, Serialize};
+
+use crate::{config, ethereum};
+
+lazy_static! {
+    // public parameters, loaded once, from configured SRS file, falling back
+    // to embedded ones when none is configured, failure is reported by `init`
+    static ref LOADED: Result<PublicParameters, String> = load(config::get().srs_path.as_deref(), *FORMAT);
+    pub static ref PUBLIC_PARAMS: &'static PublicParameters = LOADED
+        .as_ref()
+        .expect("public parameters to be loaded at startup");
+    // format of configured SRS, which also decides encoding of cell openings
+    pub static ref FORMAT: SrsFormat = config::get().srs_format;
+    // identifies SRS cells are verified with, in verification reports
+    pub static ref SRS_ID: String = id(&PUBLIC_PARAMS, *FORMAT).expect("public parameters to be loaded at startup");
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SrsFormat {
+    // dusk-plonk serialised public parameters, little endian scalars
+    Dusk,
+    // JSON SRS of Ethereum KZG ceremony, big endian scalars & blob
+    // elements laid over evaluation domain in bit reversed order
+    Ethereum,
+}
+
+impl FromStr for SrsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dusk" => Ok(SrsFormat::Dusk),
+            "ethereum" => Ok(SrsFormat::Ethereum),
+            _ => Err(format!("unknown SRS format `{}`, expected one of dusk, ethereum", s)),
+        }
+    }
+}
+
+// Loads public parameters of configured SRS, once configuration is set, so
+// that unreadable or malformed SRS fails light client at startup, rather
+// than on first verification
+pub fn init() -> Result<(), String> {
+    LOADED.as_ref().map(|_| ()).map_err(|e| e.clone())
+}
+
+// Multiproofs, whole rows, reconstruction & fraud proofs are only handled in
+// dusk encoding, over natural order domain, so they're refused, instead of
+// being checked in wrong encoding, when configured SRS is of other format
+pub fn require_dusk(what: &str) -> Result<(), String> {
+    match *FORMAT {
+        SrsFormat::Dusk => Ok(()),
+        SrsFormat::Ethereum => Err(format!("{} not supported with ethereum SRS format", what)),
+    }
+}
+
+// Reads public parameters of given format from file, or deserialises
+// embedded ones, when no path is given, which are only in dusk format
+pub fn load(path: Option<&Path>, format: SrsFormat) -> Result<PublicParameters, String> {
+    let bytes = match path {
+        Some(path) => fs::read(path)
+            .map_err(|e| format!("failed to read SRS file {} : {}", path.display(), e))?,
+        None if format == SrsFormat::Dusk => EMBEDDED.to_vec(),
+        None => return Err("SRS file must be configured for ethereum format".to_string()),
+    };
+
+    match format {
+        SrsFormat::Dusk => PublicParameters::from_bytes(&bytes)
+            .map_err(|e| format!("failed to deserialise public parameters : {:?}", e)),
+        SrsFormat::Ethereum => ethereum::parse_srs(&bytes),
+    }
+}
+
+// Format of public parameters, along with hash of their opening key i.e.
+// G1 generator, G2 generator & [τ]₂, which is all cell verification uses,
+// so same SRS gets same id, irrespective of how many powers are loaded
+pub fn id(public_params: &PublicParameters, format: SrsFormat) -> Result<String, String> {
+    let (_, opening_key) = public_params.trim(1).map_err(|e| format!("{:?}", e))?;
+
+    let mut bytes = opening_key.g.to_compressed().to_vec();
+    bytes.extend_from_slice(&opening_key.h.to_compressed());
+    bytes.extend_from_slice(&opening_key.beta_h.to_compressed());
+
+    let format = match format {
+        SrsFormat::Dusk => "dusk",
+        SrsFormat::Ethereum => "ethereum",
+    };
+    Ok(format!("{}:0x{}", format, hex::encode(blake2b(16, &[], &bytes).as_bytes())))
+}
+
+// Public parameters, serialised using dusk-plonk
+const EMBEDDED: &[u8] = &[
+    178, 84, 164, 248, 187, 227, 126, 84, 84, 157, 147, 116, 228, 246, 78, 83, 95, 179, 181,
+    97, 166, 109, 68, 108, 111, 211, 186, 151, 5, 185, 234, 30, 81, 196, 188, 1, 2, 186, 217,
+    69, 43, 179, 98, 51, 116, 15, 158, 149, 175, 3, 174, 186, 192, 85, 205, 100, 234, 159, 172,
+    170, 79, 177, 17, 38, 66, 44, 248, 91, 33, 57, 246, 138, 185, 190, 35, 160, 243, 119, 68,
+    110, 206, 81, 251, 246, 187, 192, 167, 134, 86, 253, 125, 221, 185, 58, 112, 66, 19, 192,
+    76, 14, 0, 195, 186, 171, 228, 67, 186, 65, 48, 210, 162, 177, 201, 166, 20, 105, 144, 60,
+    110, 228, 200, 98, 19, 125, 161, 56, 137, 159, 161, 247, 17, 175, 242, 236, 66, 152, 50,
+    14, 36, 30, 57, 206, 139, 100, 130, 13, 54, 180, 222, 62, 46, 179, 179, 100, 240, 227, 218,
+    22, 173, 232, 221, 28, 79, 196, 33, 75, 122, 42, 94, 102, 191, 171, 203, 204, 200, 132,
+    187, 218, 24, 216, 33, 119, 154, 154, 143, 245, 194, 238, 204, 187, 221, 85, 7, 223, 182,
+    33, 195, 239, 67, 244, 31, 199, 213, 235, 58, 6, 100, 70, 250, 92, 68, 158, 176, 45, 56,
+    149, 176, 49, 77, 114, 192, 203, 76, 125, 172, 58, 125, 196, 213, 210, 203, 163, 66, 144,
+    153, 116, 0, 71, 70, 171, 0, 0, 0, 0, 0, 0, 1, 1, 178, 84, 164, 248, 187, 227, 126, 84, 84,
+    157, 147, 116, 228, 246, 78, 83, 95, 179, 181, 97, 166, 109, 68, 108, 111, 211, 186, 151,
+    5, 185, 234, 30, 81, 196, 188, 1, 2, 186, 217, 69, 43, 179, 98, 51, 116, 15, 158, 149, 133,
+    37, 76, 200, 206, 229, 107, 117, 195, 90, 171, 239, 10, 84, 114, 1, 119, 92, 68, 246, 164,
+    183, 162, 159, 77, 106, 26, 196, 136, 177, 171, 150, 226, 37, 96, 146, 134, 81, 127, 171,
+    189, 197, 26, 227, 0, 218, 227, 222, 172, 247, 94, 2, 194, 207, 93, 142, 33, 77, 201, 215,
+    168, 109, 175, 191, 54, 158, 225, 229, 148, 24, 82, 240, 132, 135, 237, 7, 138, 96, 85, 22,
+    176, 95, 202, 117, 151, 84, 219, 112, 200, 49, 189, 75, 95, 192, 110, 183, 185, 9, 213, 15,
+    250, 57, 240, 18, 83, 254, 66, 160, 71, 229, 221, 1, 9, 77, 130, 24, 7, 218, 226, 139, 189,
+    197, 130, 109, 178, 22, 156, 168, 142, 20, 165, 206, 33, 162, 184, 194, 41, 119, 83, 90,
+    252, 198, 100, 231, 179, 132, 113, 100, 116, 108, 159, 248, 167, 22, 26, 13, 96, 150, 201,
+    172, 159, 65, 12, 152, 44, 217, 54, 123, 29, 97, 236, 1, 145, 122, 223, 48, 88, 131, 64,
+    100, 98, 172, 0, 183, 250, 168, 254, 80, 113, 203, 131, 232, 140, 34, 94, 4, 159, 195, 211,
+    187, 244, 127, 241, 162, 235, 5, 109, 157, 199, 208, 95, 25, 45, 246, 25, 243, 136, 69,
+    216, 199, 219, 105, 86, 31, 132, 62, 86, 102, 231, 243, 93, 215, 219, 66, 164, 161, 195,
+    216, 146, 53, 184, 10, 137, 2, 32, 214, 23, 121, 230, 208, 228, 182, 229, 136, 88, 212,
+    153, 210, 10, 25, 55, 43, 18, 246, 147, 198, 191, 207, 170, 26, 137, 219, 128, 163, 36,
+    179, 197, 12, 44, 185, 16, 222, 120, 152, 190, 84, 146, 212, 172, 167, 140, 238, 216, 210,
+    101, 243, 30, 51, 96, 224, 81, 112, 189, 46, 174, 192, 107, 175, 123, 10, 175, 156, 60, 20,
+    213, 125, 29, 225, 58, 182, 117, 177, 217, 85, 183, 79, 170, 67, 246, 222, 242, 217, 115,
+    68, 101, 64, 162, 188, 210, 130, 148, 221, 241, 136, 255, 167, 167, 191, 30, 107, 101, 87,
+    186, 20, 196, 63, 117, 71, 31, 48, 230, 82, 245, 1, 199, 239, 19, 109, 177, 226, 68, 105,
+    32, 37, 138, 42, 89, 237, 234, 96, 93, 9, 213, 137, 182, 213, 49, 47, 127, 51, 66, 53, 242,
+    117, 255, 81, 236, 28, 112, 183, 127, 68, 89, 47, 185, 150, 121, 62, 67, 254, 30, 123, 245,
+    233, 182, 55, 51, 247, 38, 1, 99, 132, 237, 125, 236, 31, 125, 81, 40, 204, 61, 213, 164,
+    160, 139, 68, 186, 173, 236, 251, 187, 52, 141, 64, 112, 138, 156, 238, 126, 184, 201, 60,
+    235, 254, 199, 25, 225, 219, 179, 144, 49, 178, 161, 247, 130, 181, 187, 222, 80, 53, 212,
+    220, 162, 162, 179, 255, 226, 234, 160, 147, 183, 210, 134, 152, 250, 13, 150, 221, 14,
+    188, 108, 27, 119, 222, 27, 62, 130, 242, 31, 197, 250, 58, 227, 203, 223, 203, 174, 64,
+    240, 125, 82, 9, 188, 40, 240, 210, 11, 94, 236, 27, 163, 233, 1, 204, 19, 221, 178, 182,
+    143, 102, 213, 224, 51, 203, 62, 181, 77, 127, 205, 49, 177, 68, 110, 101, 87, 88, 251,
+    174, 218, 95, 50, 234, 126, 5, 233, 66, 143, 120, 189, 191, 172, 50, 207, 213, 129, 28,
+    229, 25, 96, 86, 4, 3, 201, 68, 205, 56, 178, 131, 218, 128, 92, 91, 37, 245, 62, 151, 104,
+    61, 17, 164, 235, 84, 83, 192, 244, 205, 52, 158, 174, 132, 209, 36, 59, 17, 195, 196, 10,
+    218, 238, 66, 44, 127, 105, 199, 38, 70, 240, 38, 16, 112, 13, 35, 165, 235, 172, 110, 136,
+    217, 147, 110, 139, 249, 240, 15, 116, 216, 127, 18, 55, 200, 0, 71, 46, 200, 29, 163, 164,
+    167, 15, 252, 214, 102, 69, 237, 110, 179, 83, 253, 222, 165, 81, 248, 153, 250, 213, 207,
+    207, 199, 168, 180, 252, 32, 131, 61, 48, 169, 91, 85, 226, 180, 92, 199, 196, 96, 41, 200,
+    0, 140, 124, 16, 167, 166, 82, 117, 38, 177, 97, 224, 223, 240, 82, 251, 183, 76, 107, 154,
+    28, 203, 113, 84, 168, 17, 157, 137, 68, 61, 150, 122, 175, 152, 129, 205, 68, 228, 24,
+    222, 68, 218, 250, 136, 58, 93, 208, 223, 55, 27, 191, 205, 168, 163, 96, 229, 200, 176,
+    148, 225, 134, 17, 240, 34, 234, 55, 161, 88, 156, 91, 192, 17, 59, 131, 104, 233, 129,
+    186, 143, 174, 130, 125, 143, 218, 148, 197, 26, 239, 127, 156, 18, 96, 181, 38, 62, 205,
+    255, 3, 83, 68, 89, 109, 7, 232, 93, 16, 81, 6, 24, 23, 137, 38, 220, 72, 73, 1, 235, 75,
+    121, 173, 69, 175, 92, 228, 171, 78, 74, 129, 25, 40, 166, 29, 101, 234, 191, 183, 69, 27,
+    105, 88, 214, 244, 68, 210, 132, 125, 23, 177, 135, 0, 9, 218, 119, 114, 253, 154, 46, 252,
+    252, 73, 230, 134, 30, 124, 152, 55, 207, 138, 49, 219, 161, 228, 126, 131, 78, 128, 155,
+    219, 164, 180, 36, 150, 251, 68, 36, 72, 206, 39, 45, 254, 24, 111, 126, 166, 102, 54, 214,
+    227, 67, 175, 178, 188, 145, 168, 185, 142, 123, 183, 199, 167, 113, 201, 120, 156, 212,
+    20, 165, 8, 117, 168, 197, 143, 168, 135, 215, 88, 174, 79, 254, 71, 115, 182, 158, 91,
+    221, 187, 76, 134, 240, 74, 230, 207, 141, 112, 133, 250, 108, 47, 141, 137, 34, 248, 129,
+    34, 171, 238, 14, 107, 101, 133, 100, 244, 33, 51, 162, 32, 132, 254, 242, 157, 184, 132,
+    35, 80, 134, 123, 18, 225, 202, 235, 213, 218, 200, 55, 32, 20, 234, 72, 11, 176, 200, 220,
+    223, 241, 20, 88, 180, 10, 110, 192, 28, 85, 105, 93, 228, 134, 173, 50, 23, 115, 9, 100,
+    175, 135, 88, 53, 3, 159, 154, 172, 202, 98, 151, 47, 28, 181, 195, 66, 116, 147, 152, 215,
+    74, 1, 236, 51, 119, 136, 176, 206, 159, 202, 92, 182, 133, 46, 82, 38, 172, 89, 216, 135,
+    75, 2, 194, 151, 121, 56, 209, 126, 169, 96, 158, 65, 94, 103, 183, 234, 6, 97, 131, 66,
+    142, 109, 203, 195, 24, 66, 205, 149, 30, 144, 31, 143, 153, 245, 32, 0, 114, 244, 247, 33,
+    208, 255, 182, 139, 18, 114, 16, 70, 246, 8, 140, 35, 225, 150, 113, 66, 232, 247, 255, 0,
+    15, 175, 148, 113, 81, 216, 130, 230, 170, 122, 40, 107, 22, 43, 92, 8, 207, 46, 122, 36,
+    186, 182, 246, 16, 110, 242, 189, 142, 95, 129, 205, 72, 76, 82, 34, 185, 76, 139, 30, 57,
+    172, 49, 85, 127, 220, 202, 183, 74, 120, 157, 128, 241, 81, 109, 166, 136, 133, 45, 8,
+    117, 153, 72, 202, 77, 95, 73, 34, 198, 234, 162, 242, 152, 252, 152, 221, 201, 111, 187,
+    42, 35, 98, 5, 81, 121, 42, 40, 163, 4, 138, 167, 2, 108, 205, 174, 235, 90, 188, 55, 170,
+    132, 22, 250, 168, 130, 108, 210, 41, 250, 107, 89, 127, 151, 163, 215, 95, 143, 144, 39,
+    218, 62, 203, 152, 11, 20, 65, 149, 65, 7, 235, 10, 38, 24, 77, 88, 211, 161, 98, 47, 252,
+    129, 122, 59, 66, 201, 251, 129, 13, 198, 163, 13, 173, 114, 23, 99, 0, 208, 44, 13, 170,
+    213, 0, 253, 121, 185, 142, 214, 235, 61, 131, 186, 128, 114, 20, 51, 53, 49, 202, 180, 38,
+    49, 43, 187, 152, 136, 144, 77, 193, 162, 33, 16, 251, 229, 150, 150, 161, 13, 178, 59,
+    167, 127, 13, 121, 29, 129, 61, 31, 46, 88, 97, 249, 80, 234, 27, 109, 230, 193, 123, 30,
+    166, 195, 82, 171, 66, 76, 29, 135, 161, 109, 207, 66, 77, 199, 126, 54, 113, 163, 3, 52,
+    248, 58, 22, 203, 76, 154, 83, 56, 144, 133, 77, 241, 31, 20, 84, 73, 228, 255, 166, 126,
+    189, 142, 3, 225, 201, 50, 207, 200, 181, 229, 240, 75, 133, 159, 201, 30, 80, 57, 115,
+    128, 16, 4, 81, 91, 232, 113, 135, 226, 30, 238, 23, 235, 151, 139, 136, 115, 135, 33, 229,
+    145, 131, 106, 40, 230, 73, 154, 83, 183, 129, 178, 173, 220, 98, 17, 197, 114, 228, 195,
+    54, 131, 132, 52, 183, 78, 137, 88, 154, 28, 97, 86, 82, 197, 51, 164, 141, 97, 241, 221,
+    106, 247, 116, 48, 155, 202, 173, 9, 140, 25, 75, 229, 140, 7, 123, 42, 120, 207, 228, 62,
+    64, 247, 37, 129, 9, 94, 77, 70, 91, 128, 67, 91, 57, 69, 147, 66, 244, 214, 41, 132, 227,
+    25, 236, 171, 190, 244, 220, 55, 154, 230, 73, 98, 204, 27, 177, 64, 123, 8, 32, 84, 12,
+    106, 57, 79, 239, 207, 70, 159, 12, 87, 27, 159, 221, 67, 98, 52, 13, 100, 54, 62, 162, 56,
+    152, 42, 204, 235, 41, 103, 152, 162, 220, 2, 65, 15, 20, 183, 179, 255, 73, 188, 165, 40,
+    172, 0, 114, 181, 80, 222, 219, 247, 27, 77, 87, 249, 9, 93, 143, 81, 87, 180, 41, 156,
+    108, 161, 158, 237, 194, 115, 149, 172, 27, 220, 133, 52, 203, 45, 178, 193, 115, 218, 154,
+    169, 88, 184, 209, 198, 144, 158, 91, 107, 152, 134, 140, 181, 210, 114, 83, 48, 221, 227,
+    207, 158, 86, 240, 157, 195, 145, 243, 131, 174, 23, 105, 104, 217, 112, 86, 37, 13, 84,
+    244, 251, 116, 111, 81, 253, 237, 92, 27, 83, 105, 8, 120, 185, 68, 40, 119, 14, 159, 182,
+    156, 70, 130, 193, 158, 92, 217, 189, 24, 253, 196, 163, 249, 51, 208, 114, 51, 145, 202,
+    183, 252, 142, 217, 77, 55, 121, 173, 78, 212, 80, 133, 123, 118, 221, 149, 132, 46, 217,
+    139, 17, 198, 50, 131, 159, 4, 90, 146, 165, 113, 228, 187, 6, 213, 11, 21, 124, 81, 80,
+    57, 199, 133, 28, 85, 115, 48, 212, 187, 198, 250, 197, 191, 73, 211, 21, 142, 22, 66, 34,
+    79, 210, 169, 165, 208, 89, 119, 44, 78, 148, 206, 112, 129, 119, 12, 149, 153, 149, 95,
+    74, 173, 106, 39, 188, 53, 194, 98, 86, 23, 54, 55, 94, 253, 99, 12, 242, 89, 189, 65, 253,
+    166, 82, 11, 187, 99, 130, 68, 18, 26, 107, 32, 103, 193, 76, 22, 80, 186, 239, 70, 35, 43,
+    142, 150, 41, 50, 171, 46, 35, 142, 191, 90, 39, 248, 92, 60, 237, 139, 173, 47, 178, 148,
+    62, 190, 197, 85, 60, 120, 246, 18, 40, 0, 1, 72, 43, 137, 65, 49, 128, 223, 13, 171, 218,
+    12, 26, 68, 126, 5, 185, 158, 77, 241, 108, 101, 148, 106, 137, 242, 182, 49, 214, 131,
+    221, 224, 151, 221, 85, 40, 10, 254, 85, 102, 21, 254, 170, 255, 224, 206, 228, 91, 58,
+    139, 237, 191, 186, 237, 149, 127, 81, 42, 243, 146, 205, 252, 226, 100, 233, 224, 79, 240,
+    177, 200, 175, 90, 145, 196, 42, 161, 232, 152, 216, 165, 13, 181, 154, 195, 135, 38, 183,
+    82, 185, 222, 220, 142, 95, 81, 60, 210, 129, 14, 211, 249, 78, 255, 214, 15, 96, 219, 189,
+    62, 240, 82, 250, 182, 153, 30, 129, 216, 99, 250, 171, 19, 201, 113, 143, 37, 156, 168,
+    199, 215, 231, 39, 46, 65, 126, 127, 57, 19, 166, 229, 248, 253, 68, 83, 163, 157, 154,
+    124, 33, 110, 57, 4, 160, 113, 128, 151, 187, 133, 84, 46, 235, 30, 18, 14, 189, 238, 81,
+    236, 174, 42, 76, 58, 67, 148, 242, 169, 145, 37, 186, 78, 98, 182, 23, 184, 107, 72, 65,
+    211, 88, 64, 206, 224, 197, 143, 178, 15, 122, 178, 240, 72, 126, 205, 221, 139, 242, 72,
+    166, 142, 66, 18, 216, 101, 211, 17, 127, 190, 140, 244, 5, 151, 231, 87, 167, 207, 254,
+    176, 152, 54, 229, 235, 115, 48, 13, 250, 249, 163, 170, 134, 106, 167, 178, 180, 58, 21,
+    169, 36, 228, 226, 253, 248, 95, 176, 196, 92, 139, 136, 20, 142, 196, 171, 133, 241, 130,
+    117, 176, 89, 246, 58, 160, 227, 52, 172, 13, 193, 152, 142, 250, 244, 56, 106, 143, 24,
+    93, 7, 43, 136, 253, 189, 228, 143, 216, 142, 151, 34, 79, 219, 118, 72, 97, 116, 170, 230,
+    246, 196, 113, 150, 220, 127, 159, 106, 25, 249, 181, 6, 41, 164, 142, 16, 52, 64, 167,
+    105, 106, 240, 45, 162, 51, 175, 70, 12, 232, 154, 125, 94, 142, 160, 29, 97, 247, 133,
+    131, 227, 86, 19, 172, 182, 176, 79, 188, 204, 186, 250, 55, 44, 11, 118, 78, 50, 162, 80,
+    134, 36, 31, 250, 197, 230, 42, 14, 215, 127, 49, 253, 58, 187, 35, 175, 75, 82, 116, 91,
+    120, 9, 166, 26, 252, 41, 62, 163, 245, 219, 173, 143, 86, 139, 118, 144, 194, 195, 106,
+    145, 5, 74, 87, 55, 152, 63, 82, 162, 137, 89, 149, 139, 68, 250, 44, 0, 42, 42, 97, 198,
+    18, 64, 103, 55, 74, 82, 23, 175, 73, 62, 108, 158, 212, 192, 174, 244, 133, 234, 235, 3,
+    100, 58, 99, 172, 123, 2, 63, 70, 207, 251, 189, 173, 21, 244, 162, 142, 150, 2, 13, 143,
+    75, 54, 146, 143, 81, 136, 194, 0, 22, 214, 135, 198, 83, 19, 152, 128, 230, 184, 123, 94,
+    92, 45, 26, 208, 119, 96, 153, 202, 196, 55, 167, 159, 182, 163, 180, 152, 62, 35, 109, 9,
+    33, 109, 160, 119, 177, 83, 141, 189, 254, 124, 196, 189, 44, 79, 72, 27, 183, 76, 206,
+    219, 22, 154, 244, 134, 87, 161, 236, 221, 150, 122, 192, 159, 72, 141, 22, 92, 138, 136,
+    101, 26, 64, 91, 165, 255, 123, 221, 5, 206, 127, 248, 15, 58, 173, 199, 252, 120, 169,
+    222, 3, 34, 174, 137, 140, 189, 218, 129, 118, 30, 90, 26, 213, 60, 100, 127, 235, 30, 30,
+    155, 158, 91, 145, 130, 249, 180, 160, 76, 121, 116, 228, 68, 147, 222, 25, 135, 255, 228,
+    119, 37, 10, 139, 165, 244, 191, 126, 231, 108, 159, 197, 132, 154, 171, 192, 212, 226, 32,
+    237, 63, 244, 80, 23, 218, 100, 188, 18, 232, 236, 85, 131, 180, 237, 206, 203, 84, 233,
+    149, 24, 103, 99, 18, 61, 209, 186, 199, 225, 222, 240, 139, 80, 139, 198, 22, 123, 202,
+    100, 226, 104, 241, 253, 2, 129, 240, 106, 104, 230, 73, 89, 133, 83, 235, 92, 42, 143,
+    196, 247, 168, 149, 47, 253, 13, 19, 65, 74, 101, 48, 168, 56, 3, 178, 177, 55, 140, 151,
+    218, 125, 242, 98, 180, 178, 254, 38, 230, 119, 19, 217, 1, 50, 73, 235, 183, 123, 223,
+    214, 133, 246, 42, 65, 224, 129, 11, 116, 37, 84, 31, 133, 116, 70, 217, 45, 152, 220, 204,
+    215, 41, 74, 211, 100, 93, 41, 60, 98, 243, 81, 5, 18, 183, 186, 51, 7, 111, 41, 13, 222,
+    240, 2, 203, 216, 162, 1, 44, 91, 253, 177, 134, 168, 126, 187, 118, 7, 13, 13, 148, 163,
+    150, 121, 136, 194, 31, 152, 123, 52, 136, 218, 111, 80, 6, 244, 161, 234, 22, 94, 239, 34,
+    169, 77, 140, 90, 137, 40, 241, 157, 184, 142, 167, 48, 245, 111, 247, 159, 209, 4, 11,
+    179, 238, 59, 182, 77, 130, 88, 81, 124, 105, 231, 157, 124, 44, 72, 64, 4, 28, 66, 128,
+    110, 171, 24, 220, 167, 123, 53, 32, 211, 178, 211, 83, 201, 25, 142, 208, 40, 75, 4, 77,
+    235, 240, 240, 171, 104, 205, 159, 123, 161, 239, 133, 179, 226, 176, 66, 254, 103, 130,
+    75, 70, 242, 138, 226, 166, 248, 124, 122, 196, 53, 140, 59, 179, 241, 123, 68, 34, 208,
+    151, 90, 231, 87, 237, 248, 123, 131, 140, 78, 34, 177, 165, 136, 205, 123, 210, 187, 76,
+    250, 60, 81, 240, 64, 96, 24, 225, 183, 236, 157, 32, 107, 21, 181, 222, 193, 77, 187, 203,
+    26, 112, 157, 225, 44, 4, 216, 186, 71, 135, 18, 68, 210, 220, 66, 57, 102, 234, 2, 188,
+    183, 40, 85, 173, 175, 9, 247, 155, 236, 65, 8, 93, 99, 191, 2, 16, 73, 230, 61, 108, 46,
+    215, 147, 195, 14, 24, 190, 147, 242, 90, 186, 40, 99, 0, 102, 206, 243, 172, 241, 232,
+    104, 135, 239, 210, 107, 208, 68, 202, 149, 133, 205, 47, 66, 238, 1, 180, 171, 8, 107, 14,
+    244, 142, 209, 61, 242, 106, 34, 80, 60, 90, 218, 49, 131, 237, 118, 222, 199, 226, 194,
+    120, 194, 129, 17, 8, 176, 176, 15, 188, 206, 125, 21, 155, 121, 11, 9, 103, 73, 63, 255,
+    122, 23, 85, 229, 162, 42, 205, 172, 218, 190, 150, 6, 135, 138, 177, 3, 73, 158, 184, 224,
+    174, 38, 208, 102, 108, 163, 130, 115, 23, 29, 11, 66, 135, 152, 137, 206, 56, 18, 94, 212,
+    114, 226, 178, 174, 41, 51, 97, 102, 55, 84, 229, 34, 21, 225, 122, 175, 82, 112, 109, 120,
+    213, 82, 179, 5, 195, 188, 73, 3, 18, 123, 233, 134, 36, 1, 123, 222, 143, 245, 26, 206,
+    34, 167, 177, 26, 210, 231, 244, 167, 63, 18, 224, 128, 37, 16, 209, 25, 143, 67, 251, 97,
+    229, 78, 10, 67, 160, 27, 58, 154, 149, 6, 110, 101, 80, 79, 150, 154, 106, 36, 57, 44, 67,
+    38, 214, 46, 217, 20, 85, 247, 11, 244, 146, 124, 33, 182, 47, 254, 174, 114, 7, 16, 146,
+    126, 212, 219, 57, 153, 46, 109, 237, 255, 22, 47, 98, 248, 204, 152, 214, 217, 123, 183,
+    107, 213, 61, 240, 72, 200, 215, 165, 18, 89, 164, 66, 203, 2, 36, 132, 28, 196, 171, 136,
+    14, 214, 152, 4, 147, 90, 242, 79, 148, 201, 220, 47, 66, 202, 57, 87, 154, 249, 178, 99,
+    43, 28, 57, 38, 59, 45, 56, 224, 132, 97, 49, 174, 35, 242, 98, 192, 251, 252, 79, 20, 145,
+    245, 155, 231, 223, 191, 167, 79, 9, 59, 174, 39, 241, 215, 181, 193, 2, 171, 5, 157, 24,
+    133, 124, 242, 180, 200, 229, 149, 183, 148, 113, 113, 200, 7, 151, 229, 86, 155, 103, 95,
+    22, 110, 224, 18, 2, 183, 118, 12, 194, 220, 108, 237, 239, 210, 173, 49, 64, 188, 6, 80,
+    105, 70, 19, 10, 107, 167, 141, 7, 190, 193, 139, 236, 155, 251, 104, 154, 3, 95, 46, 12,
+    71, 129, 54, 46, 157, 175, 231, 155, 253, 200, 155, 57, 238, 130, 227, 42, 167, 235, 197,
+    63, 3, 160, 145, 211, 152, 190, 1, 146, 190, 116, 114, 59, 190, 144, 4, 79, 93, 107, 42,
+    11, 219, 60, 104, 28, 112, 180, 197, 155, 152, 106, 0, 218, 13, 249, 132, 174, 211, 82, 4,
+    64, 182, 212, 120, 79, 57, 25, 9, 90, 226, 157, 143, 155, 18, 29, 8, 136, 96, 18, 89, 155,
+    161, 37, 181, 209, 180, 185, 57, 192, 85, 137, 69, 160, 149, 101, 79, 101, 228, 87, 117,
+    26, 36, 42, 243, 89, 234, 160, 30, 104, 183, 221, 149, 9, 226, 173, 47, 93, 225, 142, 135,
+    194, 211, 123, 115, 254, 113, 181, 16, 191, 166, 82, 22, 214, 52, 238, 166, 120, 212, 157,
+    132, 30, 155, 205, 58, 85, 239, 148, 42, 102, 198, 239, 255, 245, 28, 153, 166, 165, 202,
+    245, 74, 157, 152, 46, 148, 27, 202, 142, 178, 243, 203, 236, 21, 222, 129, 112, 95, 226,
+    68, 20, 13, 191, 53, 251, 223, 150, 202, 84, 218, 78, 230, 248, 231, 44, 164, 105, 22, 58,
+    254, 113, 28, 1, 239, 64, 44, 252, 16, 118, 183, 171, 226, 237, 83, 246, 145, 85, 170, 101,
+    104, 245, 223, 70, 9, 190, 19, 188, 212, 4, 79, 31, 238, 92, 38, 240, 159, 158, 169, 169,
+    102, 161, 135, 225, 168, 247, 11, 136, 104, 202, 133, 167, 189, 2, 53, 164, 63, 214, 100,
+    57, 176, 78, 23, 255, 194, 186, 143, 150, 137, 158, 15, 103, 8, 153, 181, 162, 180, 94,
+    188, 229, 46, 173, 63, 220, 153, 215, 92, 141, 28, 40, 208, 159, 194, 103, 90, 198, 167,
+    241, 142, 34, 235, 202, 19, 66, 148, 233, 144, 87, 50, 149, 149, 32, 250, 199, 176, 213,
+    104, 131, 159, 132, 114, 167, 148, 55, 123, 151, 120, 171, 203, 248, 115, 22, 30, 9, 123,
+    81, 104, 4, 132, 2, 240, 123, 51, 71, 41, 45, 134, 114, 193, 218, 236, 53, 204, 171, 229,
+    135, 207, 217, 64, 5, 35, 79, 174, 190, 63, 27, 185, 28, 39, 254, 12, 210, 17, 68, 82, 221,
+    186, 81, 149, 47, 49, 58, 233, 53, 254, 181, 217, 243, 62, 209, 201, 212, 78, 103, 53, 37,
+    10, 252, 130, 39, 237, 66, 87, 232, 176, 183, 194, 223, 203, 60, 140, 240, 224, 68, 206,
+    128, 15, 213, 4, 204, 214, 5, 91, 124, 83, 2, 97, 198, 248, 105, 248, 179, 10, 110, 14,
+    224, 112, 212, 138, 158, 188, 5, 193, 103, 196, 53, 126, 42, 152, 63, 82, 73, 244, 65, 135,
+    96, 104, 70, 138, 144, 150, 134, 172, 135, 129, 20, 213, 164, 61, 99, 170, 22, 133, 240,
+    46, 92, 207, 17, 105, 24, 127, 91, 89, 255, 74, 132, 243, 191, 91, 213, 56, 109, 40, 175,
+    156, 7, 14, 133, 25, 231, 105, 151, 244, 159, 95, 41, 178, 160, 211, 221, 10, 2, 38, 242,
+    184, 160, 118, 191, 97, 1, 141, 240, 245, 194, 185, 98, 120, 108, 48, 127, 38, 152, 167,
+    64, 58, 47, 250, 240, 31, 120, 22, 160, 60, 22, 19, 79, 102, 21, 246, 217, 68, 20, 53, 69,
+    171, 217, 174, 104, 120, 17, 103, 16, 87, 14, 176, 29, 153, 45, 39, 8, 248, 162, 120, 197,
+    11, 48, 152, 191, 12, 97, 79, 231, 235, 98, 156, 54, 31, 62, 43, 199, 232, 15, 146, 204,
+    232, 163, 224, 150, 17, 30, 187, 118, 185, 149, 112, 4, 242, 20, 118, 118, 143, 18, 8, 183,
+    65, 34, 245, 3, 27, 248, 40, 171, 145, 2, 112, 124, 237, 98, 228, 209, 82, 184, 28, 152,
+    113, 105, 247, 228, 165, 91, 12, 83, 73, 88, 245, 80, 185, 90, 253, 34, 133, 3, 94, 40, 50,
+    203, 242, 141, 23, 32, 248, 46, 196, 9, 23, 22, 152, 43, 31, 138, 143, 75, 221, 93, 191,
+    103, 51, 191, 46, 12, 76, 99, 152, 193, 6, 232, 210, 237, 253, 182, 73, 8, 21, 173, 67, 54,
+    200, 128, 175, 14, 205, 31, 58, 121, 202, 167, 223, 40, 174, 221, 0, 133, 147, 78, 224,
+    202, 60, 195, 187, 202, 22, 132, 7, 167, 24, 34, 70, 103, 138, 214, 140, 32, 209, 226, 100,
+    151, 172, 145, 219, 82, 60, 246, 203, 145, 202, 3, 139, 118, 9, 233, 196, 229, 239, 253,
+    255, 6, 248, 224, 225, 179, 189, 6, 122, 218, 91, 104, 235, 6, 249, 207, 82, 237, 55, 174,
+    226, 128, 2, 165, 117, 65, 98, 184, 226, 237, 236, 211, 27, 62, 13, 238, 191, 194, 222,
+    255, 152, 187, 227, 5, 18, 140, 42, 141, 12, 82, 80, 7, 255, 127, 121, 134, 201, 216, 224,
+    253, 149, 117, 248, 217, 233, 45, 145, 181, 223, 212, 235, 228, 53, 149, 41, 122, 55, 33,
+    78, 245, 235, 28, 2, 6, 114, 114, 243, 216, 132, 82, 124, 92, 77, 226, 213, 144, 137, 35,
+    122, 157, 72, 51, 196, 134, 40, 233, 44, 255, 39, 107, 62, 146, 229, 163, 157, 119, 172,
+    20, 127, 239, 74, 83, 1, 129, 178, 82, 170, 57, 45, 151, 0, 124, 207, 43, 172, 90, 144, 14,
+    127, 138, 105, 41, 3, 250, 203, 109, 0, 151, 2, 159, 137, 223, 81, 252, 223, 204, 22, 233,
+    27, 238, 131, 195, 201, 57, 190, 22, 4, 202, 25, 137, 62, 87, 139, 202, 129, 58, 26, 215,
+    105, 99, 117, 117, 126, 193, 165, 224, 87, 48, 73, 71, 61, 156, 107, 20, 167, 240, 211,
+    155, 128, 183, 207, 47, 129, 186, 55, 249, 187, 69, 98, 174, 228, 118, 165, 6, 250, 249,
+    37, 163, 131, 107, 220, 100, 78, 0, 198, 104, 91, 147, 202, 89, 87, 115, 151, 57, 26, 255,
+    230, 83, 250, 20, 207, 217, 132, 122, 159, 84, 249, 247, 19, 121, 12, 11, 65, 2, 37, 48,
+    250, 115, 163, 80, 64, 177, 130, 131, 87, 30, 186, 209, 242, 182, 141, 39, 196, 12, 142, 9,
+    55, 5, 33, 212, 133, 201, 16, 26, 146, 220, 19, 135, 138, 175, 39, 193, 191, 237, 108, 81,
+    144, 246, 153, 202, 251, 165, 135, 15, 46, 160, 119, 198, 92, 182, 119, 40, 168, 191, 64,
+    26, 145, 227, 83, 18, 81, 129, 211, 183, 169, 78, 163, 71, 145, 238, 217, 125, 198, 195,
+    163, 195, 50, 220, 187, 16, 45, 67, 177, 46, 172, 134, 90, 189, 83, 57, 0, 142, 77, 172,
+    166, 178, 236, 136, 150, 197, 252, 40, 9, 251, 230, 213, 198, 234, 20, 6, 139, 115, 219,
+    181, 244, 98, 146, 222, 204, 239, 13, 29, 198, 148, 88, 231, 16, 120, 59, 161, 193, 182,
+    183, 206, 119, 236, 27, 213, 252, 215, 176, 3, 152, 236, 66, 244, 107, 63, 17, 71, 235, 44,
+    213, 62, 101, 98, 7, 232, 95, 150, 132, 220, 137, 137, 182, 32, 154, 41, 109, 138, 94, 56,
+    41, 252, 238, 128, 97, 188, 114, 234, 176, 8, 98, 55, 18, 162, 82, 121, 119, 27, 145, 60,
+    87, 142, 0, 59, 207, 50, 157, 7, 96, 187, 104, 134, 62, 221, 160, 118, 227, 159, 73, 189,
+    130, 151, 155, 6, 47, 18, 78, 38, 210, 205, 146, 76, 228, 140, 177, 56, 104, 114, 215, 150,
+    115, 156, 165, 187, 236, 75, 28, 10, 33, 193, 17, 153, 56, 244, 233, 113, 180, 90, 76, 203,
+    148, 19, 34, 43, 39, 19, 5, 209, 164, 6, 107, 69, 159, 199, 197, 104, 64, 148, 169, 3, 167,
+    110, 8, 150, 203, 191, 141, 239, 230, 222, 53, 210, 189, 87, 174, 210, 232, 63, 80, 191,
+    22, 235, 123, 180, 217, 139, 65, 212, 68, 255, 95, 204, 6, 39, 250, 218, 182, 38, 105, 222,
+    212, 73, 241, 31, 250, 110, 45, 183, 235, 172, 9, 119, 241, 126, 244, 19, 25, 74, 190, 186,
+    112, 68, 127, 123, 14, 72, 210, 27, 46, 56, 105, 97, 96, 61, 18, 43, 162, 125, 28, 61, 70,
+    56, 57, 87, 132, 253, 76, 65, 96, 176, 36, 91, 173, 143, 243, 179, 78, 5, 238, 27, 119,
+    154, 119, 181, 221, 60, 112, 156, 167, 22, 248, 199, 137, 51, 43, 145, 113, 52, 241, 55,
+    163, 185, 26, 137, 249, 210, 165, 76, 31, 161, 200, 126, 170, 158, 19, 55, 188, 231, 94,
+    118, 38, 135, 142, 222, 4, 68, 108, 154, 35, 54, 216, 85, 138, 189, 86, 19, 187, 254, 149,
+    109, 211, 49, 139, 244, 163, 1, 160, 73, 144, 49, 84, 37, 244, 219, 213, 245, 176, 160,
+    112, 92, 172, 15, 99, 91, 196, 74, 103, 247, 176, 38, 112, 0, 158, 209, 137, 52, 181, 118,
+    57, 114, 28, 143, 12, 103, 37, 113, 191, 138, 53, 22, 216, 161, 187, 110, 206, 182, 136,
+    227, 124, 222, 16, 26, 180, 19, 0, 189, 145, 233, 185, 73, 57, 167, 44, 165, 112, 8, 254,
+    239, 212, 193, 205, 81, 233, 16, 191, 229, 202, 98, 238, 208, 106, 20, 166, 198, 63, 116,
+    128, 223, 210, 201, 191, 54, 252, 115, 224, 113, 249, 181, 29, 2, 255, 169, 169, 81, 14,
+    63, 130, 4, 201, 206, 252, 23, 180, 60, 228, 21, 216, 157, 17, 121, 116, 92, 42, 7, 3, 226,
+    245, 19, 93, 164, 89, 139, 190, 22, 133, 169, 166, 216, 8, 42, 163, 187, 190, 93, 120, 106,
+    100, 79, 105, 171, 219, 71, 193, 40, 147, 149, 209, 50, 140, 200, 132, 14, 37, 187, 119,
+    34, 238, 54, 107, 234, 154, 10, 176, 22, 13, 229, 34, 19, 213, 145, 33, 215, 249, 41, 108,
+    231, 2, 127, 91, 69, 113, 33, 44, 10, 157, 64, 43, 61, 153, 169, 214, 242, 136, 229, 201,
+    94, 218, 104, 192, 154, 49, 10, 153, 96, 249, 199, 248, 224, 209, 143, 120, 205, 62, 200,
+    84, 82, 156, 239, 217, 105, 103, 18, 106, 207, 131, 218, 130, 154, 143, 181, 216, 237, 222,
+    233, 211, 18, 79, 29, 53, 204, 163, 63, 122, 106, 97, 229, 122, 172, 183, 4, 61, 92, 238,
+    58, 24, 180, 18, 40, 112, 195, 117, 107, 144, 236, 12, 53, 72, 40, 159, 64, 150, 116, 137,
+    130, 128, 2, 234, 85, 32, 18, 169, 10, 228, 13, 112, 189, 64, 145, 171, 246, 151, 174, 167,
+    175, 14, 215, 183, 197, 153, 78, 250, 45, 94, 200, 176, 174, 74, 246, 132, 99, 72, 107,
+    153, 138, 59, 236, 176, 137, 240, 135, 48, 91, 20, 206, 181, 129, 28, 193, 196, 220, 40,
+    124, 17, 178, 88, 85, 141, 15, 155, 201, 43, 203, 49, 50, 144, 41, 141, 194, 74, 156, 57,
+    19, 82, 37, 26, 8, 78, 246, 246, 187, 208, 254, 107, 133, 25, 147, 96, 92, 188, 69, 129,
+    69, 168, 66, 152, 163, 150, 48, 225, 1, 152, 47, 88, 22, 160, 29, 27, 131, 223, 206, 187,
+    92, 46, 17, 127, 46, 208, 193, 237, 155, 236, 224, 191, 66, 67, 3, 243, 55, 45, 232, 156,
+    223, 9, 57, 3, 78, 137, 21, 6, 81, 80, 21, 249, 47, 3, 237, 164, 80, 186, 33, 90, 122, 147,
+    116, 101, 122, 79, 72, 225, 121, 27, 43, 126, 251, 149, 76, 100, 0, 59, 120, 179, 199, 33,
+    141, 18, 25, 34, 222, 211, 254, 172, 42, 10, 168, 131, 97, 38, 197, 78, 131, 4, 199, 84,
+    74, 209, 183, 219, 117, 90, 29, 163, 221, 77, 139, 23, 50, 69, 129, 20, 29, 168, 171, 12,
+    40, 189, 65, 221, 158, 46, 228, 30, 199, 96, 3, 121, 174, 175, 3, 239, 178, 13, 8, 45, 10,
+    208, 205, 251, 133, 62, 229, 125, 105, 30, 203, 190, 32, 122, 196, 131, 13, 172, 250, 27,
+    187, 208, 239, 122, 198, 67, 181, 85, 57, 77, 54, 154, 21, 44, 90, 69, 116, 137, 196, 5,
+    95, 36, 16, 222, 95, 224, 233, 212, 200, 59, 92, 156, 161, 141, 160, 161, 199, 186, 246,
+    32, 119, 75, 233, 160, 168, 28, 39, 248, 86, 105, 50, 191, 244, 215, 230, 251, 85, 58, 216,
+    20, 178, 89, 144, 213, 60, 39, 212, 119, 75, 171, 132, 47, 9, 181, 32, 220, 17, 50, 178,
+    106, 60, 75, 52, 184, 12, 117, 153, 44, 255, 12, 82, 182, 102, 72, 208, 235, 39, 61, 107,
+    34, 68, 203, 245, 53, 237, 121, 94, 72, 155, 190, 202, 82, 181, 155, 95, 12, 163, 229, 47,
+    134, 37, 204, 10, 242, 9, 37, 131, 238, 56, 157, 124, 18, 98, 154, 156, 162, 21, 150, 217,
+    140, 77, 120, 167, 55, 30, 140, 211, 33, 242, 29, 103, 18, 154, 166, 13, 24, 50, 113, 176,
+    91, 74, 16, 160, 49, 9, 170, 25, 187, 82, 190, 64, 110, 231, 35, 17, 225, 95, 34, 246, 209,
+    188, 91, 229, 26, 50, 136, 153, 167, 22, 122, 194, 75, 44, 245, 213, 187, 214, 237, 159,
+    248, 29, 96, 11, 226, 115, 139, 29, 180, 124, 85, 128, 98, 245, 247, 220, 230, 219, 14,
+    220, 188, 181, 84, 96, 107, 213, 224, 97, 18, 207, 89, 163, 136, 0, 46, 57, 168, 9, 67,
+    200, 56, 0, 155, 25, 67, 193, 64, 144, 201, 109, 100, 37, 161, 80, 146, 200, 117, 49, 167,
+    140, 81, 4, 70, 7, 9, 107, 231, 0, 61, 139, 89, 161, 92, 135, 74, 208, 161, 217, 63, 197,
+    12, 8, 244, 12, 182, 219, 169, 45, 241, 240, 145, 16, 177, 30, 254, 68, 208, 9, 205, 227,
+    6, 113, 64, 60, 167, 215, 240, 92, 77, 227, 136, 131, 140, 206, 124, 248, 44, 99, 161, 188,
+    88, 228, 36, 23, 253, 252, 35, 253, 227, 108, 115, 102, 175, 61, 28, 71, 100, 116, 43, 96,
+    23, 207, 79, 131, 17, 234, 50, 83, 234, 206, 187, 146, 154, 132, 87, 144, 235, 164, 210,
+    201, 105, 87, 135, 164, 228, 95, 142, 178, 231, 20, 12, 19, 20, 75, 210, 233, 1, 164, 171,
+    149, 148, 227, 44, 18, 93, 244, 251, 31, 7, 224, 55, 50, 215, 91, 209, 143, 191, 117, 207,
+    2, 67, 144, 33, 69, 144, 74, 46, 215, 50, 119, 135, 214, 163, 15, 56, 122, 132, 133, 72,
+    183, 103, 217, 140, 5, 252, 237, 5, 129, 171, 16, 137, 231, 151, 125, 94, 58, 56, 66, 229,
+    113, 151, 142, 33, 237, 175, 234, 162, 86, 103, 221, 195, 7, 250, 5, 208, 53, 138, 131, 76,
+    57, 10, 135, 83, 100, 111, 98, 153, 46, 142, 124, 112, 119, 1, 208, 165, 21, 129, 20, 166,
+    3, 198, 174, 63, 205, 252, 221, 238, 23, 158, 144, 246, 239, 66, 180, 77, 211, 253, 74, 37,
+    206, 153, 22, 120, 207, 136, 121, 32, 252, 132, 109, 52, 245, 223, 32, 70, 59, 133, 248,
+    156, 244, 153, 17, 177, 22, 141, 107, 142, 16, 176, 210, 95, 77, 61, 131, 80, 236, 145,
+    103, 222, 209, 142, 238, 193, 233, 62, 140, 156, 8, 48, 88, 250, 195, 159, 199, 2, 81, 86,
+    25, 45, 190, 59, 11, 177, 66, 143, 251, 227, 139, 168, 171, 45, 82, 177, 50, 59, 244, 100,
+    220, 102, 215, 38, 86, 120, 101, 216, 56, 190, 36, 105, 9, 33, 215, 69, 73, 83, 6, 130, 75,
+    118, 236, 165, 153, 92, 215, 104, 35, 52, 185, 15, 81, 255, 117, 102, 31, 58, 30, 208, 134,
+    182, 159, 166, 89, 211, 133, 163, 76, 172, 219, 122, 118, 244, 90, 88, 233, 177, 159, 23,
+    134, 47, 82, 188, 161, 150, 14, 242, 156, 75, 189, 231, 151, 243, 65, 18, 237, 33, 234, 65,
+    159, 198, 64, 70, 252, 212, 243, 33, 3, 63, 230, 167, 82, 190, 101, 183, 200, 105, 128, 14,
+    252, 34, 223, 111, 185, 253, 59, 244, 6, 186, 75, 66, 44, 84, 27, 57, 87, 189, 67, 204,
+    105, 143, 83, 224, 89, 17, 106, 201, 43, 90, 159, 105, 76, 121, 111, 79, 64, 225, 87, 183,
+    115, 44, 103, 179, 39, 173, 234, 194, 30, 90, 255, 2, 196, 135, 117, 214, 58, 160, 126, 15,
+    40, 45, 58, 166, 97, 136, 192, 182, 241, 142, 164, 17, 14, 167, 195, 127, 76, 138, 214,
+    190, 9, 11, 37, 35, 126, 138, 90, 150, 34, 228, 1, 210, 236, 75, 172, 161, 123, 34, 145,
+    137, 71, 39, 34, 187, 158, 189, 155, 11, 242, 92, 231, 206, 35, 78, 45, 169, 254, 199, 80,
+    127, 4, 246, 45, 226, 86, 142, 9, 54, 200, 232, 161, 49, 108, 188, 220, 143, 253, 128, 29,
+    151, 246, 23, 242, 90, 169, 17, 254, 214, 99, 97, 246, 96, 160, 9, 56, 218, 109, 26, 147,
+    33, 141, 187, 129, 118, 54, 144, 43, 255, 20, 188, 99, 128, 168, 89, 2, 174, 228, 21, 18,
+    170, 178, 219, 135, 163, 95, 135, 97, 97, 89, 151, 92, 250, 4, 2, 30, 160, 226, 217, 130,
+    154, 111, 209, 226, 15, 248, 180, 243, 95, 223, 14, 131, 68, 113, 6, 154, 138, 203, 219,
+    187, 93, 75, 42, 248, 155, 88, 17, 121, 32, 88, 156, 148, 179, 221, 32, 155, 255, 111, 202,
+    216, 153, 160, 42, 16, 99, 155, 231, 160, 138, 127, 235, 230, 222, 127, 226, 28, 243, 77,
+    87, 154, 185, 59, 75, 123, 225, 53, 158, 125, 61, 78, 127, 40, 57, 142, 120, 67, 128, 64,
+    195, 151, 142, 84, 193, 241, 46, 95, 126, 41, 80, 167, 17, 248, 57, 232, 153, 40, 19, 181,
+    146, 53, 47, 89, 13, 85, 90, 157, 47, 11, 59, 196, 188, 201, 190, 96, 134, 107, 7, 110,
+    222, 28, 34, 141, 74, 181, 142, 104, 76, 23, 184, 122, 106, 152, 142, 160, 71, 150, 56, 26,
+    158, 219, 116, 167, 87, 98, 169, 95, 69, 131, 61, 208, 60, 31, 141, 196, 148, 205, 87, 20,
+    85, 200, 77, 214, 119, 159, 40, 186, 61, 60, 107, 197, 142, 69, 201, 23, 23, 15, 172, 203,
+    11, 130, 229, 227, 60, 196, 86, 247, 163, 60, 119, 166, 125, 207, 25, 232, 68, 75, 149, 28,
+    7, 170, 133, 239, 21, 138, 133, 177, 28, 103, 168, 118, 118, 185, 163, 32, 43, 129, 44,
+    130, 231, 22, 4, 6, 174, 195, 161, 161, 204, 226, 77, 232, 42, 253, 250, 173, 123, 184,
+    166, 32, 45, 78, 67, 154, 115, 87, 151, 42, 245, 90, 98, 163, 119, 236, 213, 103, 43, 140,
+    245, 91, 15, 27, 133, 55, 162, 10, 37, 70, 36, 89, 18, 253, 43, 67, 135, 88, 106, 100, 218,
+    147, 92, 158, 47, 136, 207, 88, 114, 7, 44, 145, 251, 44, 86, 43, 228, 136, 177, 239, 216,
+    216, 116, 162, 148, 19, 99, 3, 206, 120, 110, 141, 76, 35, 54, 139, 85, 199, 157, 46, 147,
+    146, 35, 162, 167, 151, 240, 194, 127, 58, 78, 245, 118, 206, 187, 96, 135, 40, 91, 171,
+    240, 229, 153, 124, 244, 54, 143, 4, 175, 84, 27, 234, 77, 189, 81, 11, 128, 123, 232, 158,
+    97, 209, 71, 113, 61, 255, 244, 176, 149, 81, 127, 231, 146, 145, 152, 130, 242, 225, 13,
+    169, 169, 40, 98, 206, 85, 3, 10, 50, 74, 155, 44, 116, 83, 228, 55, 183, 29, 210, 48, 68,
+    169, 50, 103, 3, 138, 146, 115, 33, 160, 132, 15, 214, 170, 98, 136, 30, 190, 38, 15, 47,
+    131, 7, 138, 175, 131, 224, 105, 196, 85, 10, 196, 62, 67, 31, 124, 5, 207, 201, 44, 201,
+    146, 129, 72, 174, 251, 204, 94, 33, 188, 34, 200, 28, 77, 20, 104, 147, 240, 187, 2, 92,
+    75, 110, 140, 132, 241, 124, 225, 203, 185, 230, 122, 140, 93, 195, 125, 46, 53, 2, 157,
+    125, 18, 149, 49, 39, 18, 162, 97, 10, 112, 222, 241, 119, 210, 168, 163, 27, 92, 139, 40,
+    87, 155, 228, 19, 110, 21, 172, 14, 76, 105, 129, 123, 44, 124, 231, 236, 209, 130, 39, 74,
+    152, 87, 2, 246, 169, 165, 85, 59, 240, 27, 2, 152, 126, 95, 98, 196, 206, 132, 216, 62,
+    156, 13, 172, 109, 242, 105, 196, 212, 43, 174, 198, 154, 194, 5, 80, 146, 164, 212, 0,
+    190, 111, 3, 4, 200, 221, 150, 114, 168, 199, 58, 161, 18, 33, 61, 46, 216, 175, 108, 28,
+    168, 193, 183, 159, 171, 242, 92, 118, 45, 247, 99, 242, 169, 12, 58, 132, 159, 143, 203,
+    20, 8, 244, 196, 154, 238, 170, 104, 217, 103, 74, 66, 173, 85, 43, 137, 23, 219, 123, 238,
+    186, 34, 100, 55, 21, 184, 254, 252, 183, 163, 195, 195, 212, 194, 165, 17, 239, 168, 124,
+    106, 200, 148, 81, 116, 40, 121, 210, 246, 22, 5, 175, 173, 173, 189, 60, 41, 139, 251, 99,
+    203, 12, 251, 253, 164, 233, 182, 135, 32, 236, 51, 192, 55, 236, 233, 169, 90, 55, 232, 5,
+    179, 173, 118, 100, 150, 219, 247, 207, 207, 20, 99, 8, 151, 186, 63, 106, 226, 144, 80, 6,
+    65, 75, 172, 219, 212, 117, 252, 240, 125, 165, 148, 155, 137, 15, 49, 208, 187, 185, 171,
+    130, 71, 112, 127, 231, 203, 182, 190, 109, 101, 116, 237, 181, 221, 190, 68, 235, 247,
+    149, 0, 32, 46, 246, 53, 207, 24, 89, 6, 194, 21, 14, 165, 150, 107, 58, 213, 239, 138,
+    194, 173, 20, 160, 128, 232, 252, 220, 55, 184, 226, 188, 3, 118, 126, 172, 68, 149, 149,
+    30, 119, 29, 95, 32, 163, 194, 66, 105, 138, 237, 141, 122, 234, 198, 116, 122, 26, 241,
+    222, 159, 64, 166, 209, 154, 59, 181, 69, 38, 9, 56, 169, 135, 212, 83, 58, 82, 116, 62,
+    168, 33, 0, 169, 158, 113, 92, 34, 118, 89, 128, 253, 218, 224, 199, 5, 119, 112, 253, 5,
+    243, 20, 103, 91, 76, 96, 170, 224, 22, 117, 71, 205, 143, 106, 30, 135, 237, 228, 118,
+    238, 228, 147, 19, 160, 65, 199, 217, 214, 60, 83, 18, 56, 246, 124, 218, 196, 129, 217,
+    158, 145, 157, 186, 237, 177, 42, 182, 67, 211, 239, 10, 60, 124, 177, 36, 92, 23, 40, 207,
+    99, 53, 99, 220, 99, 86, 216, 76, 112, 72, 17, 173, 156, 76, 162, 149, 16, 228, 1, 69, 39,
+    255, 226, 51, 214, 28, 27, 220, 43, 25, 108, 22, 163, 54, 200, 94, 219, 151, 231, 254, 59,
+    57, 56, 156, 142, 56, 193, 242, 61, 247, 97, 13, 153, 193, 154, 235, 211, 231, 250, 167,
+    129, 147, 176, 44, 180, 191, 232, 108, 255, 92, 238, 209, 174, 189, 116, 162, 164, 91, 39,
+    215, 209, 161, 124, 254, 195, 7, 233, 209, 156, 242, 49, 23, 179, 198, 28, 132, 45, 160,
+    64, 46, 2, 32, 173, 149, 135, 43, 162, 160, 170, 222, 8, 219, 47, 56, 119, 132, 95, 129, 3,
+    187, 107, 68, 92, 187, 122, 119, 170, 20, 163, 91, 182, 108, 70, 48, 167, 211, 86, 36, 207,
+    208, 80, 46, 141, 171, 57, 231, 201, 179, 150, 63, 84, 236, 153, 192, 171, 150, 15, 195,
+    164, 16, 251, 138, 55, 68, 228, 157, 97, 91, 60, 33, 198, 122, 155, 112, 44, 219, 11, 165,
+    73, 230, 31, 97, 99, 53, 156, 32, 67, 29, 46, 161, 225, 206, 3, 132, 45, 22, 244, 102, 165,
+    186, 88, 195, 193, 183, 149, 85, 19, 249, 217, 147, 118, 8, 141, 122, 74, 125, 1, 241, 116,
+    58, 60, 44, 177, 4, 90, 65, 158, 182, 82, 18, 220, 13, 103, 152, 150, 136, 114, 40, 43,
+    102, 9, 189, 129, 89, 53, 39, 251, 35, 71, 37, 165, 179, 241, 111, 73, 171, 79, 158, 194,
+    41, 168, 48, 127, 138, 178, 17, 138, 20, 12, 205, 190, 121, 119, 85, 96, 114, 86, 194, 121,
+    80, 146, 114, 198, 112, 216, 34, 70, 236, 98, 207, 35, 88, 146, 248, 100, 47, 10, 1, 137,
+    174, 191, 223, 12, 113, 41, 135, 121, 71, 60, 121, 105, 212, 225, 29, 50, 138, 245, 50, 38,
+    109, 191, 112, 132, 81, 38, 40, 221, 255, 71, 163, 208, 95, 169, 161, 117, 6, 17, 62, 114,
+    238, 5, 71, 253, 32, 118, 45, 64, 139, 171, 0, 237, 245, 23, 87, 96, 113, 226, 76, 177, 71,
+    58, 174, 109, 125, 40, 147, 211, 38, 2, 240, 47, 172, 77, 70, 108, 222, 139, 44, 76, 97,
+    170, 160, 184, 124, 34, 172, 20, 139, 49, 222, 243, 169, 6, 37, 79, 152, 135, 127, 196, 64,
+    185, 91, 15, 87, 194, 175, 106, 226, 251, 248, 149, 249, 85, 64, 247, 75, 157, 99, 93, 99,
+    85, 39, 160, 209, 77, 185, 80, 194, 70, 98, 117, 164, 196, 88, 77, 114, 61, 85, 203, 31,
+    212, 60, 63, 161, 193, 92, 148, 45, 127, 244, 203, 78, 204, 169, 202, 186, 3, 50, 52, 252,
+    121, 148, 88, 7, 123, 206, 15, 194, 251, 92, 47, 9, 25, 217, 156, 241, 42, 215, 83, 221,
+    215, 221, 16, 202, 29, 220, 111, 69, 93, 111, 193, 169, 116, 117, 192, 215, 75, 182, 144,
+    169, 230, 42, 73, 225, 67, 39, 37, 134, 16, 119, 42, 177, 190, 31, 184, 77, 149, 200, 35,
+    165, 53, 81, 29, 239, 36, 47, 16, 57, 225, 73, 62, 233, 92, 253, 115, 196, 229, 77, 11,
+    171, 168, 165, 154, 144, 172, 95, 130, 140, 127, 70, 66, 87, 84, 38, 229, 29, 41, 6, 170,
+    127, 153, 253, 132, 61, 169, 170, 89, 122, 190, 151, 38, 144, 106, 209, 19, 85, 21, 208,
+    22, 65, 233, 175, 36, 75, 244, 49, 64, 153, 244, 196, 172, 5, 76, 229, 162, 217, 37, 18,
+    105, 71, 249, 252, 228, 49, 107, 30, 130, 40, 39, 70, 161, 206, 111, 18, 44, 82, 152, 167,
+    0, 216, 63, 254, 52, 224, 71, 106, 1, 239, 134, 101, 198, 194, 165, 196, 10, 144, 214, 155,
+    145, 12, 254, 167, 205, 206, 40, 135, 137, 107, 146, 183, 30, 115, 138, 251, 212, 190, 24,
+    154, 59, 157, 141, 144, 43, 238, 100, 37, 81, 126, 195, 43, 218, 90, 37, 59, 51, 124, 216,
+    253, 235, 78, 176, 96, 58, 132, 254, 145, 209, 223, 30, 230, 16, 95, 100, 113, 72, 6, 110,
+    135, 246, 141, 60, 215, 53, 248, 34, 144, 101, 142, 246, 124, 188, 3, 120, 50, 57, 111, 88,
+    237, 225, 30, 170, 97, 216, 201, 103, 212, 73, 250, 211, 98, 212, 131, 95, 159, 210, 194,
+    115, 10, 108, 68, 62, 251, 206, 14, 195, 74, 240, 220, 242, 184, 184, 222, 50, 49, 239,
+    204, 175, 14, 147, 44, 36, 167, 109, 158, 165, 169, 9, 83, 102, 3, 15, 88, 250, 135, 10,
+    255, 190, 198, 224, 160, 173, 234, 213, 4, 241, 75, 161, 150, 151, 197, 6, 78, 205, 180,
+    112, 70, 31, 17, 59, 99, 188, 10, 198, 66, 199, 255, 26, 244, 119, 21, 241, 153, 9, 103,
+    166, 1, 144, 91, 139, 210, 65, 196, 144, 152, 73, 253, 126, 167, 133, 218, 0, 167, 160,
+    164, 75, 223, 153, 61, 176, 43, 153, 127, 156, 127, 63, 146, 135, 136, 109, 27, 29, 243,
+    47, 144, 90, 61, 26, 253, 66, 126, 45, 248, 232, 218, 243, 67, 157, 197, 56, 67, 191, 128,
+    163, 229, 225, 173, 145, 154, 28, 252, 194, 37, 222, 113, 10, 8, 213, 217, 113, 172, 159,
+    63, 123, 203, 223, 79, 170, 66, 181, 42, 13, 208, 109, 87, 196, 194, 217, 192, 158, 245,
+    123, 177, 137, 143, 194, 142, 10, 166, 236, 141, 194, 229, 35, 167, 205, 249, 60, 16, 253,
+    192, 169, 152, 146, 108, 37, 206, 48, 203, 28, 26, 36, 1, 216, 196, 144, 20, 184, 154, 198,
+    106, 183, 177, 159, 96, 176, 160, 76, 175, 179, 36, 123, 128, 17, 11, 51, 222, 50, 90, 230,
+    218, 24, 147, 110, 218, 138, 112, 166, 146, 229, 142, 219, 52, 243, 91, 139, 24, 16, 238,
+    100, 23, 131, 140, 3, 147, 125, 173, 158, 48, 114, 122, 101, 14, 106, 251, 56, 116, 172,
+    138, 254, 43, 177, 53, 189, 24, 73, 80, 113, 28, 197, 149, 152, 65, 205, 239, 186, 65, 217,
+    124, 123, 198, 202, 221, 118, 28, 232, 58, 199, 109, 119, 219, 82, 134, 164, 32, 94, 123,
+    242, 210, 190, 126, 105, 182, 93, 129, 188, 6, 161, 62, 172, 255, 48, 170, 249, 243, 19,
+    85, 123, 150, 140, 179, 117, 208, 173, 73, 27, 234, 143, 205, 221, 150, 57, 113, 224, 188,
+    149, 35, 152, 57, 164, 76, 29, 74, 189, 252, 198, 67, 79, 183, 54, 126, 27, 205, 100, 131,
+    172, 51, 171, 229, 144, 146, 66, 163, 24, 11, 45, 181, 160, 216, 107, 25, 229, 81, 38, 14,
+    133, 246, 246, 28, 75, 153, 41, 169, 111, 224, 101, 132, 206, 16, 204, 81, 167, 239, 146,
+    75, 216, 95, 93, 252, 105, 106, 177, 210, 83, 142, 222, 158, 177, 67, 67, 237, 132, 145,
+    252, 163, 0, 140, 141, 204, 24, 239, 202, 149, 0, 127, 74, 31, 194, 86, 213, 212, 120, 10,
+    112, 169, 32, 215, 143, 180, 216, 190, 133, 246, 194, 184, 151, 122, 181, 93, 115, 30, 46,
+    197, 174, 70, 214, 9, 247, 134, 69, 216, 75, 141, 176, 115, 83, 178, 48, 151, 85, 100, 203,
+    213, 51, 118, 133, 99, 213, 248, 47, 250, 203, 126, 138, 184, 21, 89, 186, 74, 116, 82, 55,
+    126, 5, 228, 20, 48, 195, 112, 152, 49, 235, 4, 40, 119, 150, 28, 135, 228, 250, 146, 245,
+    217, 133, 191, 62, 179, 62, 82, 66, 224, 170, 204, 58, 216, 1, 145, 63, 81, 241, 76, 26,
+    114, 59, 227, 9, 163, 171, 74, 216, 254, 109, 6, 19, 57, 65, 83, 63, 40, 26, 30, 29, 56,
+    195, 65, 156, 251, 235, 183, 87, 205, 148, 152, 114, 251, 104, 186, 145, 112, 243, 150,
+    233, 245, 74, 133, 120, 249, 0, 110, 143, 33, 3, 36, 4, 153, 206, 65, 92, 183, 139, 101,
+    33, 85, 170, 146, 174, 93, 74, 24, 20, 191, 8, 25, 215, 148, 203, 147, 72, 135, 146, 242,
+    212, 177, 231, 177, 233, 215, 249, 234, 22, 48, 21, 140, 54, 58, 174, 123, 135, 245, 106,
+    161, 97, 197, 198, 190, 46, 127, 238, 241, 101, 248, 247, 239, 69, 112, 42, 174, 151, 195,
+    86, 176, 97, 255, 18, 149, 87, 152, 240, 97, 75, 72, 68, 136, 135, 94, 44, 102, 216, 240,
+    121, 177, 165, 28, 204, 253, 48, 197, 82, 163, 41, 248, 104, 215, 186, 235, 48, 143, 235,
+    213, 89, 211, 138, 104, 204, 48, 98, 50, 221, 235, 147, 49, 157, 165, 103, 156, 248, 52,
+    125, 160, 115, 93, 167, 209, 225, 38, 42, 169, 120, 114, 229, 95, 14, 242, 222, 178, 183,
+    196, 224, 89, 134, 109, 122, 183, 30, 99, 22, 8, 214, 37, 60, 83, 97, 202, 20, 187, 226,
+    54, 132, 19, 96, 177, 250, 13, 102, 74, 249, 188, 16, 150, 58, 188, 169, 39, 118, 171, 156,
+    74, 119, 93, 49, 238, 66, 164, 184, 52, 39, 80, 83, 24, 183, 12, 250, 159, 103, 49, 196,
+    194, 3, 122, 84, 187, 20, 236, 235, 205, 93, 128, 130, 165, 9, 121, 118, 239, 159, 197, 67,
+    153, 173, 138, 40, 110, 143, 165, 17, 240, 250, 93, 232, 159, 27, 51, 195, 111, 39, 79, 21,
+    136, 99, 21, 76, 224, 247, 72, 142, 21, 249, 94, 226, 2, 201, 91, 132, 163, 178, 72, 86,
+    146, 169, 91, 250, 5, 84, 46, 142, 250, 115, 158, 60, 112, 55, 111, 180, 221, 23, 76, 161,
+    138, 37, 86, 165, 14, 117, 234, 113, 8, 201, 132, 51, 138, 14, 106, 84, 92, 1, 84, 138, 88,
+    90, 65, 240, 35, 66, 45, 148, 178, 70, 52, 195, 172, 226, 28, 11, 178, 91, 213, 253, 33,
+    190, 204, 244, 93, 25, 169, 14, 8, 10, 29, 15, 238, 43, 108, 23, 52, 16, 173, 219, 148,
+    245, 210, 145, 62, 38, 129, 165, 120, 10, 73, 225, 62, 205, 236, 19, 145, 52, 43, 2, 102,
+    132, 5, 112, 163, 180, 77, 26, 205, 95, 128, 81, 233, 56, 88, 15, 149, 101, 115, 151, 190,
+    138, 54, 159, 236, 0, 186, 239, 107, 160, 137, 59, 19, 96, 75, 1, 52, 253, 104, 80, 172,
+    255, 29, 70, 145, 18, 173, 210, 71, 208, 32, 208, 117, 54, 17, 47, 62, 23, 20, 82, 130,
+    210, 56, 162, 169, 80, 49, 135, 7, 26, 196, 117, 71, 21, 134, 107, 87, 36, 180, 125, 4,
+    194, 219, 253, 201, 165, 45, 62, 121, 91, 11, 246, 152, 212, 76, 17, 162, 42, 101, 69, 227,
+    241, 50, 0, 22, 240, 160, 129, 59, 129, 55, 56, 168, 83, 237, 108, 84, 159, 151, 43, 241,
+    1, 67, 109, 244, 211, 4, 20, 18, 38, 194, 186, 135, 138, 3, 146, 140, 96, 93, 233, 142,
+    191, 40, 148, 168, 163, 145, 2, 103, 57, 177, 26, 4, 60, 142, 152, 167, 108, 42, 205, 106,
+    247, 129, 147, 173, 144, 214, 6, 75, 26, 23, 39, 6, 237, 79, 1, 109, 52, 233, 195, 231, 97,
+    76, 18, 65, 223, 226, 255, 140, 40, 170, 196, 218, 161, 164, 249, 162, 21, 167, 153, 113,
+    111, 134, 88, 123, 117, 173, 63, 86, 43, 84, 184, 30, 183, 56, 235, 48, 234, 164, 29, 85,
+    236, 142, 119, 78, 196, 121, 38, 65, 227, 129, 2, 239, 151, 56, 102, 171, 224, 113, 192,
+    90, 146, 162, 65, 1, 222, 10, 145, 162, 73, 229, 61, 226, 102, 40, 243, 255, 229, 87, 73,
+    18, 85, 184, 37, 158, 8, 59, 163, 246, 148, 18, 127, 21, 130, 223, 220, 10, 50, 125, 253,
+    149, 31, 146, 137, 171, 42, 115, 33, 170, 95, 23, 33, 117, 75, 247, 94, 166, 151, 174, 181,
+    175, 101, 127, 171, 2, 217, 47, 158, 155, 2, 72, 217, 251, 67, 61, 189, 106, 24, 139, 47,
+    225, 135, 6, 131, 44, 42, 165, 221, 123, 30, 252, 77, 129, 7, 148, 169, 20, 63, 128, 13,
+    193, 27, 210, 183, 149, 223, 133, 230, 80, 241, 87, 199, 237, 166, 124, 34, 199, 135, 131,
+    88, 252, 46, 211, 225, 212, 182, 183, 191, 138, 107, 131, 220, 115, 7, 195, 146, 45, 14,
+    138, 89, 149, 247, 122, 225, 133, 157, 244, 152, 221, 250, 218, 252, 160, 21, 152, 252, 23,
+    227, 52, 206, 75, 160, 205, 176, 242, 165, 93, 77, 254, 65, 84, 71, 81, 98, 151, 211, 60,
+    169, 242, 177, 64, 4, 36, 58, 123, 43, 50, 173, 134, 79, 41, 246, 127, 144, 195, 137, 198,
+    118, 41, 80, 90, 212, 30, 106, 93, 213, 168, 110, 243, 231, 252, 157, 92, 153, 157, 12,
+    237, 168, 101, 74, 7, 178, 178, 101, 47, 223, 193, 29, 45, 137, 26, 75, 106, 1, 78, 98,
+    180, 86, 41, 69, 239, 35, 8, 149, 173, 60, 170, 214, 216, 255, 12, 220, 221, 187, 141, 148,
+    153, 27, 247, 194, 2, 129, 129, 111, 188, 75, 225, 206, 176, 117, 19, 224, 141, 32, 100,
+    164, 235, 21, 154, 224, 80, 227, 130, 217, 171, 55, 220, 194, 7, 158, 98, 128, 166, 202,
+    104, 223, 177, 88, 244, 183, 56, 237, 61, 105, 0, 58, 42, 10, 224, 168, 176, 96, 109, 61,
+    225, 44, 27, 176, 76, 88, 53, 201, 4, 175, 99, 218, 92, 41, 117, 121, 151, 3, 130, 175,
+    245, 144, 86, 127, 249, 100, 251, 189, 61, 83, 126, 154, 54, 223, 241, 102, 81, 136, 156,
+    228, 108, 176, 151, 188, 155, 1, 78, 228, 42, 223, 200, 100, 137, 19, 187, 39, 240, 100,
+    141, 13, 126, 48, 144, 130, 244, 127, 146, 89, 174, 143, 164, 42, 8, 201, 124, 139, 59, 89,
+    229, 43, 187, 253, 201, 174, 123, 66, 43, 63, 61, 240, 158, 41, 209, 106, 243, 242, 46,
+    255, 51, 59, 157, 56, 157, 67, 140, 101, 163, 88, 103, 210, 90, 117, 215, 29, 135, 64, 128,
+    239, 107, 125, 182, 148, 209, 43, 70, 246, 181, 117, 29, 79, 0, 108, 46, 226, 224, 247, 37,
+    46, 185, 7, 200, 191, 154, 22, 64, 78, 47, 66, 148, 19, 51, 54, 250, 225, 146, 186, 167,
+    183, 123, 42, 128, 2, 162, 200, 37, 8, 238, 255, 211, 161, 215, 171, 88, 121, 153, 6, 5,
+    209, 26, 145, 113, 90, 115, 65, 167, 236, 248, 255, 7, 236, 153, 170, 176, 4, 96, 42, 251,
+    198, 211, 163, 25, 76, 231, 8, 99, 20, 20, 185, 222, 114, 136, 169, 52, 219, 109, 175, 52,
+    117, 210, 70, 11, 126, 110, 72, 178, 61, 61, 248, 3, 48, 87, 105, 55, 16, 161, 53, 145, 15,
+    86, 32, 220, 154, 192, 119, 4, 83, 22, 176, 187, 177, 162, 162, 149, 166, 209, 97, 207,
+    172, 134, 59, 254, 228, 117, 191, 87, 210, 178, 16, 212, 238, 155, 8, 147, 135, 214, 188,
+    151, 163, 94, 98, 58, 78, 135, 233, 76, 113, 173, 182, 238, 175, 74, 103, 228, 230, 27, 67,
+    248, 228, 35, 66, 175, 4, 39, 121, 142, 226, 141, 173, 245, 5, 112, 104, 176, 244, 172, 16,
+    102, 110, 8, 40, 168, 132, 12, 163, 197, 232, 209, 142, 193, 82, 57, 39, 135, 68, 200, 50,
+    15, 127, 239, 251, 189, 119, 34, 14, 170, 118, 34, 89, 210, 201, 26, 57, 235, 35, 184, 94,
+    63, 79, 245, 125, 40, 18, 4, 222, 250, 155, 236, 138, 60, 164, 45, 109, 96, 203, 228, 240,
+    242, 92, 202, 53, 199, 133, 139, 201, 79, 125, 58, 211, 204, 128, 221, 79, 132, 217, 125,
+    207, 12, 81, 163, 120, 250, 38, 164, 196, 62, 8, 147, 125, 21, 83, 163, 184, 1, 22, 10,
+    126, 7, 204, 125, 109, 161, 164, 39, 31, 121, 36, 177, 209, 117, 234, 111, 206, 58, 155,
+    140, 56, 165, 56, 147, 96, 215, 252, 205, 212, 115, 42, 5, 151, 48, 175, 152, 18, 60, 212,
+    62, 88, 218, 92, 32, 49, 197, 61, 224, 152, 210, 216, 173, 252, 245, 208, 119, 246, 71,
+    202, 210, 214, 159, 184, 234, 0, 190, 168, 197, 214, 152, 232, 176, 164, 54, 228, 52, 162,
+    103, 26, 119, 175, 175, 71, 160, 29, 113, 168, 162, 212, 187, 120, 26, 143, 145, 108, 188,
+    132, 172, 36, 73, 228, 58, 178, 169, 125, 86, 105, 12, 60, 130, 68, 209, 221, 90, 130, 80,
+    175, 139, 88, 198, 2, 31, 164, 54, 32, 139, 76, 2, 57, 15, 226, 173, 49, 179, 244, 25, 156,
+    80, 53, 114, 242, 70, 158, 120, 247, 60, 50, 75, 62, 248, 157, 15, 230, 172, 208, 202, 74,
+    240, 130, 4, 238, 8, 20, 137, 30, 13, 0, 86, 198, 193, 158, 33, 75, 45, 39, 157, 0, 252,
+    134, 175, 43, 236, 238, 73, 194, 141, 163, 201, 15, 11, 122, 82, 163, 216, 254, 162, 160,
+    227, 194, 86, 142, 217, 6, 234, 42, 151, 75, 23, 140, 36, 138, 59, 71, 206, 118, 20, 133,
+    152, 241, 51, 38, 202, 89, 61, 165, 168, 197, 172, 224, 44, 209, 165, 189, 37, 165, 155,
+    30, 146, 57, 118, 60, 213, 65, 91, 99, 178, 213, 77, 82, 211, 229, 180, 89, 65, 1, 51, 134,
+    157, 100, 233, 125, 228, 52, 178, 154, 218, 69, 234, 232, 4, 64, 167, 254, 20, 212, 133,
+    158, 168, 250, 242, 235, 105, 74, 162, 87, 14, 154, 80, 1, 38, 159, 134, 249, 249, 168,
+    193, 56, 210, 54, 31, 5, 167, 90, 242, 106, 56, 191, 142, 127, 111, 99, 81, 0, 153, 32, 60,
+    155, 65, 107, 190, 205, 110, 4, 168, 240, 179, 159, 103, 153, 154, 25, 155, 11, 234, 128,
+    94, 240, 222, 71, 63, 72, 0, 108, 146, 171, 145, 173, 56, 17, 141, 103, 152, 120, 56, 44,
+    130, 93, 237, 34, 56, 84, 156, 15, 154, 5, 192, 101, 116, 56, 7, 122, 150, 103, 149, 8,
+    123, 77, 178, 194, 107, 163, 176, 75, 131, 101, 191, 64, 147, 202, 237, 100, 103, 248, 91,
+    200, 146, 160, 57, 65, 108, 246, 245, 90, 160, 250, 2, 245, 191, 133, 110, 250, 201, 1,
+    158, 152, 174, 126, 180, 37, 179, 248, 239, 145, 109, 183, 27, 250, 126, 72, 240, 15, 132,
+    75, 170, 168, 125, 23, 97, 84, 174, 55, 199, 239, 5, 206, 101, 131, 227, 68, 160, 29, 208,
+    57, 185, 107, 1, 134, 214, 47, 242, 115, 78, 9, 208, 31, 168, 163, 168, 107, 27, 33, 134,
+    83, 206, 213, 54, 111, 53, 190, 134, 123, 178, 239, 102, 129, 155, 208, 136, 40, 181, 64,
+    155, 217, 7, 121, 134, 15, 207, 122, 145, 149, 73, 23, 77, 40, 119, 110, 85, 239, 236, 57,
+    2, 125, 85, 220, 132, 115, 218, 120, 58, 188, 0, 144, 117, 191, 243, 46, 51, 105, 188, 3,
+    16, 154, 81, 103, 230, 121, 52, 251, 219, 83, 74, 46, 202, 101, 182, 212, 49, 74, 110, 253,
+    178, 234, 209, 227, 203, 31, 171, 224, 231, 3, 67, 199, 164, 183, 42, 243, 83, 124, 197,
+    131, 205, 191, 101, 43, 42, 124, 238, 195, 118, 13, 190, 59, 213, 72, 88, 170, 195, 212, 6,
+    134, 47, 62, 54, 64, 255, 118, 146, 106, 184, 106, 163, 145, 41, 48, 212, 65, 58, 50, 66,
+    109, 172, 203, 243, 175, 55, 206, 232, 240, 172, 152, 48, 172, 85, 81, 37, 211, 138, 113,
+    74, 65, 247, 107, 183, 91, 227, 212, 249, 57, 44, 36, 143, 128, 49, 217, 57, 140, 196, 113,
+    33, 157, 137, 59, 202, 106, 230, 60, 117, 48, 140, 219, 18, 130, 90, 163, 167, 28, 45, 91,
+    242, 12, 145, 173, 225, 103, 39, 60, 68, 254, 149, 43, 219, 61, 154, 191, 70, 139, 10, 198,
+    82, 205, 213, 204, 29, 60, 153, 141, 196, 51, 72, 127, 175, 18, 81, 157, 187, 118, 135,
+    168, 66, 71, 14, 5, 178, 116, 239, 35, 169, 170, 98, 51, 209, 18, 203, 205, 220, 55, 19,
+    69, 177, 66, 67, 16, 63, 68, 137, 173, 18, 251, 35, 11, 175, 124, 2, 30, 180, 137, 6, 202,
+    188, 72, 159, 192, 189, 165, 182, 23, 2, 42, 234, 158, 13, 130, 151, 180, 138, 212, 12,
+    131, 166, 22, 156, 235, 112, 66, 176, 24, 8, 54, 209, 184, 5, 102, 126, 100, 170, 149, 236,
+    31, 92, 137, 90, 85, 250, 147, 89, 132, 194, 13, 173, 140, 129, 189, 163, 129, 6, 69, 110,
+    60, 6, 249, 85, 208, 30, 193, 184, 52, 254, 35, 233, 70, 94, 250, 221, 99, 233, 98, 217,
+    56, 102, 53, 47, 41, 255, 194, 198, 123, 98, 241, 127, 204, 26, 201, 16, 203, 210, 134, 1,
+    103, 55, 99, 185, 107, 212, 207, 44, 97, 71, 61, 70, 103, 63, 89, 22, 16, 55, 222, 75, 190,
+    83, 31, 132, 220, 141, 215, 162, 97, 118, 231, 154, 223, 20, 168, 218, 200, 151, 153, 141,
+    134, 131, 245, 155, 2, 225, 223, 243, 228, 43, 16, 148, 44, 48, 86, 162, 7, 111, 112, 50,
+    37, 231, 20, 241, 192, 208, 70, 64, 154, 8, 79, 216, 126, 157, 3, 82, 177, 93, 149, 186,
+    127, 77, 228, 136, 206, 206, 122, 208, 225, 137, 255, 85, 147, 244, 216, 108, 173, 227,
+    141, 167, 170, 82, 229, 64, 136, 5, 41, 226, 68, 44, 147, 158, 47, 38, 8, 251, 222, 197,
+    66, 71, 65, 237, 82, 100, 107, 240, 144, 133, 254, 175, 56, 201, 22, 200, 124, 121, 241,
+    164, 64, 243, 248, 252, 201, 44, 114, 24, 37, 166, 132, 30, 137, 171, 88, 26, 183, 99, 233,
+    120, 113, 37, 220, 74, 57, 56, 128, 22, 133, 217, 180, 121, 181, 17, 116, 215, 230, 21,
+    122, 41, 33, 79, 64, 151, 142, 213, 166, 70, 248, 58, 28, 253, 4, 34, 239, 74, 56, 132, 95,
+    114, 233, 232, 247, 40, 77, 48, 232, 19, 67, 232, 128, 199, 157, 23, 75, 66, 116, 245, 102,
+    166, 150, 11, 232, 227, 43, 105, 53, 22, 194, 244, 218, 223, 250, 79, 248, 171, 92, 98, 92,
+    189, 233, 85, 88, 51, 214, 166, 42, 60, 124, 46, 249, 120, 215, 76, 86, 247, 204, 9, 153,
+    53, 4, 153, 13, 153, 32, 40, 192, 200, 39, 15, 165, 46, 54, 217, 203, 27, 183, 76, 54, 230,
+    246, 17, 215, 27, 58, 97, 228, 182, 82, 45, 131, 215, 72, 170, 142, 156, 178, 108, 236,
+    169, 7, 134, 245, 228, 156, 49, 77, 15, 57, 158, 193, 227, 31, 150, 175, 29, 44, 162, 53,
+    70, 11, 235, 193, 81, 213, 3, 242, 104, 155, 149, 174, 23, 180, 121, 207, 135, 220, 94,
+    205, 158, 187, 174, 226, 219, 7, 2, 148, 111, 170, 46, 231, 51, 251, 192, 100, 58, 250,
+    129, 54, 25, 103, 103, 13, 5, 10, 14, 57, 156, 184, 83, 54, 91, 24, 143, 110, 168, 125, 22,
+    39, 253, 109, 215, 190, 238, 127, 237, 132, 187, 247, 171, 28, 201, 217, 9, 63, 108, 137,
+    190, 244, 56, 3, 192, 162, 67, 19, 137, 96, 202, 128, 50, 136, 97, 248, 17, 120, 6, 152,
+    83, 137, 114, 178, 44, 53, 24, 33, 86, 71, 33, 96, 21, 74, 12, 155, 32, 77, 93, 230, 143,
+    158, 235, 125, 29, 101, 170, 142, 8, 120, 149, 95, 43, 223, 149, 130, 104, 48, 210, 226, 0,
+    22, 180, 165, 27, 155, 95, 255, 21, 188, 115, 138, 63, 201, 118, 189, 34, 78, 229, 31, 91,
+    232, 96, 27, 159, 209, 25, 82, 147, 125, 55, 196, 70, 229, 51, 251, 112, 164, 73, 133, 215,
+    244, 159, 80, 52, 166, 152, 21, 78, 249, 188, 26, 1, 250, 53, 197, 100, 65, 49, 98, 159,
+    229, 173, 37, 113, 154, 92, 32, 44, 152, 88, 94, 11, 97, 105, 245, 150, 128, 21, 69, 93,
+    163, 79, 152, 2, 1, 216, 95, 221, 100, 112, 191, 183, 70, 93, 178, 40, 196, 62, 87, 68,
+    185, 161, 244, 28, 5, 119, 249, 25, 95, 135, 26, 31, 128, 155, 91, 27, 4, 169, 217, 251,
+    10, 34, 63, 160, 126, 56, 9, 24, 131, 58, 110, 13, 180, 134, 96, 146, 215, 163, 168, 215,
+    181, 2, 39, 135, 44, 132, 90, 246, 115, 38, 58, 57, 237, 160, 54, 247, 134, 109, 97, 230,
+    249, 167, 155, 222, 158, 104, 28, 91, 245, 88, 219, 140, 211, 173, 202, 33, 210, 151, 94,
+    32, 98, 65, 38, 99, 112, 166, 148, 82, 44, 132, 21, 209, 245, 140, 171, 190, 100, 200, 235,
+    227, 216, 215, 45, 18, 14, 96, 98, 66, 10, 116, 30, 64, 171, 252, 214, 123, 113, 167, 90,
+    164, 35, 20, 222, 13, 96, 199, 127, 244, 48, 48, 235, 161, 184, 5, 100, 3, 94, 64, 224, 64,
+    72, 88, 30, 125, 95, 190, 203, 218, 174, 235, 180, 81, 201, 32, 43, 108, 24, 165, 25, 211,
+    228, 94, 180, 221, 210, 48, 160, 168, 163, 88, 23, 35, 6, 177, 29, 241, 204, 180, 7, 115,
+    139, 13, 225, 152, 151, 111, 38, 141, 41, 154, 178, 22, 60, 142, 121, 127, 211, 59, 216,
+    128, 128, 137, 242, 40, 50, 0, 153, 179, 42, 246, 106, 32, 156, 175, 102, 176, 3, 228, 99,
+    180, 132, 254, 233, 236, 120, 194, 250, 32, 4, 96, 11, 22, 50, 120, 73, 204, 53, 112, 252,
+    203, 99, 38, 154, 7, 27, 43, 226, 1, 238, 236, 148, 111, 211, 76, 230, 40, 78, 170, 84,
+    250, 182, 229, 180, 153, 75, 213, 161, 232, 145, 249, 183, 246, 52, 145, 118, 248, 121,
+    193, 241, 231, 146, 197, 80, 18, 51, 35, 154, 248, 42, 98, 144, 225, 194, 59, 246, 133, 75,
+    34, 254, 130, 9, 15, 125, 105, 20, 194, 3, 4, 138, 114, 190, 148, 12, 136, 251, 145, 228,
+    153, 65, 244, 37, 81, 89, 111, 147, 243, 240, 246, 180, 135, 224, 203, 28, 19, 133, 215,
+    87, 251, 220, 89, 207, 134, 118, 50, 32, 125, 204, 208, 232, 10, 22, 189, 24, 131, 78, 124,
+    251, 196, 145, 185, 84, 89, 167, 64, 199, 160, 87, 186, 16, 217, 230, 183, 182, 170, 112,
+    141, 64, 164, 37, 67, 156, 125, 41, 118, 248, 159, 152, 65, 130, 3, 102, 53, 135, 99, 157,
+    38, 252, 133, 237, 156, 95, 192, 199, 207, 44, 23, 142, 179, 179, 151, 228, 158, 235, 203,
+    227, 58, 27, 193, 30, 164, 188, 39, 106, 190, 81, 140, 176, 112, 157, 228, 244, 44, 122,
+    77, 200, 13, 255, 134, 138, 164, 188, 121, 153, 166, 252, 57, 163, 100, 11, 67, 16, 197,
+    215, 210, 241, 182, 223, 66, 244, 100, 198, 211, 235, 145, 95, 172, 237, 34, 122, 35, 192,
+    232, 215, 89, 192, 134, 222, 1, 177, 191, 154, 71, 144, 212, 131, 255, 80, 192, 59, 143,
+    191, 13, 220, 78, 118, 112, 118, 84, 77, 187, 143, 75, 123, 183, 238, 70, 102, 119, 168,
+    218, 255, 234, 62, 167, 67, 81, 52, 126, 159, 11, 101, 54, 167, 143, 169, 211, 230, 155,
+    191, 94, 216, 201, 174, 228, 174, 207, 33, 38, 254, 15, 218, 240, 69, 247, 180, 197, 191,
+    22, 29, 174, 102, 150, 182, 242, 126, 69, 73, 236, 121, 31, 224, 197, 237, 217, 217, 177,
+    35, 67, 253, 133, 210, 24, 83, 235, 143, 178, 17, 29, 139, 199, 54, 91, 241, 216, 128, 30,
+    111, 60, 190, 24, 170, 92, 38, 54, 106, 229, 135, 12, 66, 184, 201, 177, 93, 167, 112, 111,
+    237, 27, 29, 45, 113, 81, 153, 160, 247, 74, 197, 196, 169, 234, 130, 155, 101, 119, 53,
+    134, 121, 242, 146, 179, 229, 159, 28, 248, 64, 85, 240, 144, 225, 201, 21, 39, 132, 21,
+    155, 254, 220, 139, 226, 29, 57, 213, 216, 171, 205, 204, 5, 179, 44, 115, 83, 50, 53, 175,
+    86, 164, 221, 71, 105, 203, 245, 20, 185, 236, 247, 178, 129, 4, 208, 101, 161, 142, 104,
+    102, 55, 162, 174, 172, 194, 2, 64, 204, 12, 26, 143, 152, 161, 163, 108, 123, 103, 68,
+    210, 81, 170, 85, 29, 1, 70, 100, 32, 139, 76, 36, 34, 177, 127, 81, 102, 138, 178, 152,
+    14, 1, 250, 235, 218, 51, 250, 70, 61, 16, 70, 208, 96, 160, 181, 71, 86, 162, 161, 141,
+    54, 183, 12, 162, 53, 31, 215, 111, 151, 80, 225, 241, 177, 34, 240, 38, 142, 11, 114, 116,
+    4, 151, 80, 122, 231, 56, 228, 130, 132, 176, 112, 101, 117, 86, 30, 109, 202, 56, 186, 14,
+    245, 104, 46, 191, 189, 103, 215, 71, 97, 165, 171, 187, 122, 177, 42, 196, 106, 128, 89,
+    129, 185, 207, 209, 37, 162, 211, 239, 106, 18, 183, 85, 242, 231, 51, 188, 185, 126, 86,
+    63, 48, 7, 89, 13, 164, 218, 196, 229, 133, 254, 244, 243, 67, 110, 249, 88, 206, 225, 185,
+    162, 185, 29, 31, 147, 65, 75, 25, 230, 53, 255, 219, 15, 255, 52, 50, 183, 148, 68, 89,
+    185, 1, 208, 229, 35, 113, 95, 243, 50, 200, 30, 115, 154, 18, 48, 96, 192, 175, 201, 188,
+    19, 127, 4, 122, 196, 45, 186, 138, 130, 169, 132, 135, 252, 155, 56, 121, 248, 110, 225,
+    191, 245, 7, 6, 15, 53, 62, 151, 180, 221, 86, 184, 140, 181, 198, 32, 61, 141, 236, 201,
+    32, 186, 246, 147, 35, 120, 142, 206, 20, 202, 153, 9, 189, 117, 194, 195, 147, 129, 105,
+    132, 114, 150, 197, 175, 54, 168, 145, 15, 5, 150, 63, 65, 59, 189, 105, 178, 90, 14, 131,
+    184, 240, 100, 167, 135, 210, 96, 30, 47, 207, 77, 218, 158, 166, 127, 116, 198, 220, 201,
+    124, 142, 181, 88, 182, 8, 150, 54, 84, 108, 125, 102, 20, 165, 65, 123, 84, 128, 231, 125,
+    216, 193, 212, 221, 137, 141, 185, 48, 201, 231, 58, 89, 28, 12, 68, 254, 253, 128, 88,
+    210, 28, 56, 255, 198, 44, 127, 190, 182, 164, 6, 252, 125, 132, 153, 170, 165, 134, 243,
+    4, 162, 68, 81, 150, 74, 167, 120, 179, 15, 2, 156, 186, 75, 81, 209, 97, 219, 208, 248,
+    235, 228, 217, 37, 164, 213, 141, 81, 20, 224, 47, 64, 138, 83, 7, 162, 142, 165, 180, 30,
+    242, 33, 91, 141, 167, 123, 242, 63, 22, 77, 102, 52, 66, 44, 181, 64, 206, 7, 104, 79,
+    160, 108, 88, 59, 151, 95, 41, 186, 160, 222, 234, 37, 118, 124, 52, 191, 16, 151, 141,
+    147, 115, 115, 243, 164, 228, 122, 156, 233, 37, 32, 45, 102, 144, 206, 234, 153, 123, 58,
+    15, 14, 190, 51, 181, 52, 157, 151, 195, 24, 191, 108, 26, 180, 178, 7, 166, 243, 43, 165,
+    158, 138, 183, 180, 91, 133, 108, 21, 241, 133, 31, 99, 180, 232, 213, 65, 33, 175, 205,
+    253, 59, 180, 151, 28, 215, 6, 197, 143, 81, 134, 33, 181, 102, 108, 76, 46, 114, 63, 91,
+    129, 242, 137, 119, 66, 141, 22, 53, 1, 127, 64, 183, 120, 241, 224, 248, 163, 215, 244,
+    177, 198, 22, 125, 76, 238, 100, 239, 139, 120, 8, 138, 167, 129, 171, 209, 47, 237, 159,
+    39, 124, 218, 186, 105, 159, 76, 212, 163, 234, 60, 239, 6, 174, 16, 137, 89, 185, 196,
+    175, 9, 235, 189, 143, 71, 168, 78, 206, 53, 124, 73, 102, 31, 72, 1, 24, 14, 14, 107, 121,
+    230, 177, 8, 185, 100, 223, 98, 114, 21, 163, 52, 161, 28, 101, 193, 113, 241, 47, 38, 10,
+    229, 235, 114, 216, 170, 140, 145, 98, 77, 82, 244, 187, 110, 74, 117, 68, 135, 75, 107,
+    26, 165, 51, 105, 250, 223, 141, 94, 8, 156,
+];
@@ -0,0 +1,165 @@
+use dusk_plonk::fft::EvaluationDomain;
+use dusk_plonk::prelude::BlsScalar;
+
+use crate::{params, row};
+
+// Cell of data matrix, whose evaluation is already known ( verified )
+pub struct KnownCell {
+    pub row: usize,
+    pub col: usize,
+    pub data: BlsScalar,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    // commitment, cells or dimensions couldn't be parsed
+    Malformed(String),
+    // some column has less than half of its cells known
+    InsufficientCells { col: usize, known: usize, required: usize },
+    // known cells of column don't lie on a low degree polynomial
+    NotLowDegree { col: usize },
+}
+
+// Multiplies out vanishing polynomial i.e. Z(x) = Π (x - ω^i), for all
+// given domain points, returning its coefficients in increasing degree
+pub fn vanishing_polynomial(points: &[BlsScalar]) -> Vec<BlsScalar> {
+    let mut coeffs = vec![BlsScalar::one()];
+
+    for point in points {
+        let mut next = vec![BlsScalar::zero(); coeffs.len() + 1];
+        for (j, c) in coeffs.iter().enumerate() {
+            next[j + 1] += c;
+            next[j] -= c * point;
+        }
+        coeffs = next;
+    }
+
+    coeffs
+}
+
+// Recovers all evaluations of polynomial of degree < `domain.size() / 2`, over
+// given domain, from evaluations at any half of domain points
+//
+// Erasures are handled by multiplying with vanishing polynomial Z of missing
+// points, so that (E · Z)(x) = (D · Z)(x) holds on whole domain, then dividing
+// out Z on a coset, where it doesn't vanish
+pub fn recover(domain: &EvaluationDomain, evals: &[Option<BlsScalar>]) -> Option<Vec<BlsScalar>> {
+    let n = domain.size();
+    let degree = n / 2;
+
+    let elements: Vec<BlsScalar> = domain.elements().collect();
+    let missing: Vec<BlsScalar> = evals
+        .iter()
+        .zip(elements.iter())
+        .filter(|(e, _)| e.is_none())
+        .map(|(_, w)| *w)
+        .collect();
+
+    if missing.is_empty() {
+        let evals: Vec<BlsScalar> = evals.iter().map(|e| e.unwrap()).collect();
+        if domain.ifft(&evals)[degree..].iter().any(|c| *c != BlsScalar::zero()) {
+            return None;
+        }
+
+        return Some(evals);
+    }
+
+    let z = vanishing_polynomial(&missing);
+    let z_evals = domain.fft(&z);
+
+    let ez: Vec<BlsScalar> = evals
+        .iter()
+        .zip(z_evals.iter())
+        .map(|(e, z)| e.unwrap_or_else(BlsScalar::zero) * z)
+        .collect();
+    let ez_coeffs = domain.ifft(&ez);
+
+    let ez_coset = domain.coset_fft(&ez_coeffs);
+    let z_coset = domain.coset_fft(&z);
+
+    let d_coset: Vec<BlsScalar> = ez_coset
+        .iter()
+        .zip(z_coset.iter())
+        .map(|(ez, z)| ez * z.invert().unwrap())
+        .collect();
+    let d = domain.coset_ifft(&d_coset);
+
+    // recovered polynomial must be of low degree, otherwise known
+    // evaluations are not part of a valid codeword
+    if d[degree..].iter().any(|c| *c != BlsScalar::zero()) {
+        return None;
+    }
+
+    let recovered = domain.fft(&d);
+    if evals.iter().zip(recovered.iter()).any(|(e, r)| e.map(|e| e != *r).unwrap_or(false)) {
+        return None;
+    }
+
+    Some(recovered)
+}
+
+// Reconstructs whole extended data matrix, column by column, from given
+// known cells, where each column is of `total_rows * 2` cells
+//
+// Returns matrix in row major order
+pub fn reconstruct(total_rows: usize, total_cols: usize, cells: &[KnownCell]) -> Result<Vec<Vec<BlsScalar>>, Error> {
+    params::require_dusk("reconstruction is").map_err(Error::Malformed)?;
+
+    let extended_rows = total_rows * 2;
+    let col_eval_domain = EvaluationDomain::new(extended_rows).map_err(|e| Error::Malformed(format!("{:?}", e)))?;
+    if col_eval_domain.size() != extended_rows {
+        return Err(Error::Malformed(format!("{} rows is not a power of 2", total_rows)));
+    }
+
+    let mut columns = vec![vec![None; extended_rows]; total_cols];
+    for cell in cells {
+        if cell.row >= extended_rows || cell.col >= total_cols {
+            return Err(Error::Malformed(format!("cell ({}, {}) out of bounds", cell.row, cell.col)));
+        }
+
+        columns[cell.col][cell.row] = Some(cell.data);
+    }
+
+    let mut matrix = vec![vec![BlsScalar::zero(); total_cols]; extended_rows];
+    for (col, column) in columns.iter().enumerate() {
+        let known = column.iter().filter(|e| e.is_some()).count();
+        if known < total_rows {
+            return Err(Error::InsufficientCells {
+                col,
+                known,
+                required: total_rows,
+            });
+        }
+
+        let recovered = recover(&col_eval_domain, column).ok_or(Error::NotLowDegree { col })?;
+        for (row, eval) in recovered.into_iter().enumerate() {
+            matrix[row][col] = eval;
+        }
+    }
+
+    Ok(matrix)
+}
+
+// Checks each reconstructed row against its 48 bytes commitment, sliced
+// out of header commitment, returning indices of rows which don't match
+pub fn mismatching_rows(matrix: &[Vec<BlsScalar>], commitment: &[u8], total_cols: usize) -> Result<Vec<usize>, Error> {
+    if commitment.len() < matrix.len() * 48 {
+        return Err(Error::Malformed(format!(
+            "commitment of length {}, expected {}",
+            commitment.len(),
+            matrix.len() * 48
+        )));
+    }
+
+    let mut mismatching = Vec::new();
+    for (i, evals) in matrix.iter().enumerate() {
+        let expected = row::parse_commitment(&commitment[i * 48..(i + 1) * 48]).map_err(Error::Malformed)?;
+        let found = row::commit(evals, total_cols).map_err(Error::Malformed)?;
+
+        if found != expected {
+            mismatching.push(i);
+        }
+    }
+
+    Ok(mismatching)
+}
@@ -0,0 +1,276 @@
+// Report of sampled cells of block, their outcome & timing & confidence
+// gained from them, which is kept in memory & persisted under storage path,
+// as CBOR & served over API, either as JSON or CBOR
+//
+// Cells of same block get verified over several calls i.e. one per sampling
+// round & for cells served by peers, all of them are merged into one report
+// per block hash
+//
+// Reports of recently verified blocks are served from memory, storage only
+// backs them up, for older blocks & across restarts, so failing to read or
+// write it never loses confidence gained
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use lru::LruCache;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::chain::Hash;
+use crate::scheme::Scheme;
+use crate::{config, params};
+
+// At max these many latest reports are kept in memory, least recently
+// used ones are evicted & read back from storage, when asked for
+const MAX_REPORTS: usize = 4096;
+
+lazy_static! {
+    // reports are merged in memory, one block at a time, storage is written
+    // to after releasing it
+    static ref REPORTS: Mutex<LruCache<Hash, BlockVerificationReport>> = Mutex::new(LruCache::new(MAX_REPORTS));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Verified,
+    // proof was well formed, but didn't verify
+    Failed,
+    // proof or commitment couldn't be parsed
+    Malformed,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SampledCell {
+    pub row: u16,
+    pub col: u16,
+    pub outcome: Outcome,
+    // time taken for checking proof of cell
+    pub micros: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockVerificationReport {
+    pub number: u64,
+    #[serde(serialize_with = "to_hex", deserialize_with = "from_hex")]
+    pub hash: Hash,
+    // dimensions of original data matrix, sampled cells are of extended one
+    pub total_rows: usize,
+    pub total_cols: usize,
+    pub cells: Vec<SampledCell>,
+    // time taken for verifying all sampled cells, across all calls
+    pub micros: u64,
+    // out of 100
+    pub confidence: f64,
+    pub scheme: Scheme,
+    // public parameters cells were verified with, see `params::id`
+    pub srs: String,
+}
+
+fn to_hex<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{}", hex::encode(hash)))
+}
+
+fn from_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    let hash = String::deserialize(deserializer)?;
+    let bytes = hex::decode(hash.trim_start_matches("0x")).map_err(serde::de::Error::custom)?;
+
+    let mut out = [0u8; 32];
+    if bytes.len() != out.len() {
+        return Err(serde::de::Error::custom(format!("hash of length {}, expected 32", bytes.len())));
+    }
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+// Confidence ( out of 100 ) that block can be reconstructed, given `verified`
+// distinct cells, sampled without replacement, out of extended matrix of
+// `total_rows * 2` rows & `total_cols` columns, as `sampling::sample_cells`
+// does
+//
+// Each column is extended to twice as many rows, so it's recovered out of any
+// `total_rows` of its cells. Least withholding, which makes block unrecoverable,
+// is then `total_rows + 1` cells of single column & confidence is chance of
+// sampling at least one of those. It reaches 100 only once at most `total_rows`
+// cells are left unverified
+pub fn confidence(total_rows: usize, total_cols: usize, verified: usize) -> f64 {
+    let total = total_rows * 2 * total_cols;
+    let served = total.saturating_sub(total_rows + 1);
+
+    let missed = (0..verified.min(total)).fold(1.0, |p, i| p * served.saturating_sub(i) as f64 / (total - i) as f64);
+    (1.0 - missed) * 100.0
+}
+
+impl BlockVerificationReport {
+    pub fn new(
+        number: u64,
+        hash: Hash,
+        total_rows: usize,
+        total_cols: usize,
+        scheme: Scheme,
+        cells: Vec<SampledCell>,
+        duration: Duration,
+    ) -> BlockVerificationReport {
+        let mut report = BlockVerificationReport {
+            number,
+            hash,
+            total_rows,
+            total_cols,
+            cells,
+            micros: duration.as_micros() as u64,
+            confidence: 0.0,
+            scheme,
+            srs: params::SRS_ID.clone(),
+        };
+        report.confidence = confidence(total_rows, total_cols, report.verified());
+        report
+    }
+
+    // Distinct cells, which were verified
+    pub fn verified(&self) -> usize {
+        self.cells.iter().filter(|c| c.outcome == Outcome::Verified).count()
+    }
+
+    // Adds cells of later verification of same block, cell sampled again
+    // keeps its verified outcome, if it was ever verified
+    pub fn merge(&mut self, other: BlockVerificationReport) {
+        for cell in other.cells {
+            match self.cells.iter_mut().find(|c| c.row == cell.row && c.col == cell.col) {
+                Some(known) if known.outcome == Outcome::Verified => {}
+                Some(known) => *known = cell,
+                None => self.cells.push(cell),
+            }
+        }
+
+        self.micros += other.micros;
+        self.confidence = confidence(self.total_rows, self.total_cols, self.verified());
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("report to be serialisable")
+    }
+
+    pub fn to_cbor(&self) -> Vec<u8> {
+        serde_cbor::to_vec(self).expect("report to be serialisable")
+    }
+
+    pub fn from_cbor(bytes: &[u8]) -> Result<BlockVerificationReport, String> {
+        serde_cbor::from_slice(bytes).map_err(|e| format!("failed to decode report : {}", e))
+    }
+}
+
+// Reports are kept under `<storage path>/reports`, one file per block hash
+fn path_of(dir: &Path, hash: &Hash) -> PathBuf {
+    dir.join("reports").join(format!("0x{}.cbor", hex::encode(hash)))
+}
+
+// Reads report of block from storage, `None` if it's not yet verified
+pub fn load(dir: &Path, hash: &Hash) -> Result<Option<BlockVerificationReport>, String> {
+    let path = path_of(dir, hash);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let bytes = fs::read(&path).map_err(|e| format!("failed to read report {} : {}", path.display(), e))?;
+    BlockVerificationReport::from_cbor(&bytes).map(Some)
+}
+
+pub fn persist(dir: &Path, report: &BlockVerificationReport) -> Result<(), String> {
+    let path = path_of(dir, &report.hash);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("failed to create {} : {}", parent.display(), e))?;
+    }
+
+    fs::write(&path, report.to_cbor()).map_err(|e| format!("failed to write report {} : {}", path.display(), e))
+}
+
+fn warn(msg: String) {
+    if config::log_enabled(config::LogLevel::Warn) {
+        println!("❌  {}", msg);
+    }
+}
+
+// Stored report of block, unreadable one is logged & treated as missing
+fn load_or_warn(dir: &Path, hash: &Hash) -> Option<BlockVerificationReport> {
+    match load(dir, hash) {
+        Ok(report) => report,
+        Err(e) => {
+            warn(e);
+            None
+        }
+    }
+}
+
+// Stored report of block, read without holding lock, only if it's not in memory
+fn stored(dir: &Path, hash: &Hash) -> Option<BlockVerificationReport> {
+    if REPORTS.lock().unwrap().contains(hash) {
+        return None;
+    }
+
+    load_or_warn(dir, hash)
+}
+
+// Merged report of block, `None` if no cell of it is yet verified
+pub fn get(hash: &Hash) -> Option<BlockVerificationReport> {
+    let dir = config::get().storage_path;
+    let stored = stored(&dir, hash);
+
+    let mut reports = REPORTS.lock().unwrap();
+    if let Some(report) = reports.get(hash) {
+        return Some(report.clone());
+    }
+
+    let report = stored?;
+    reports.put(*hash, report.clone());
+    Some(report)
+}
+
+// Merges report into one already known for same block, returning merged one,
+// which is persisted under configured storage path, on best effort basis
+//
+// Lock is held only while merging in memory, so that slow storage doesn't
+// hold up other verifications
+pub fn record(report: BlockVerificationReport) -> BlockVerificationReport {
+    let dir = config::get().storage_path;
+    let stored = stored(&dir, &report.hash);
+
+    let merged = {
+        let mut reports = REPORTS.lock().unwrap();
+
+        let merged = match reports.pop(&report.hash).or(stored) {
+            // dimensions are same for same block hash, unless it was stored by
+            // some earlier, misbehaving run, in which case it's replaced
+            Some(mut known) if known.total_rows == report.total_rows && known.total_cols == report.total_cols => {
+                known.merge(report);
+                known
+            }
+            _ => report,
+        };
+        reports.put(merged.hash, merged.clone());
+        merged
+    };
+
+    if let Err(e) = persist(&dir, &merged) {
+        warn(format!("Failed to persist report of #{} : {}", merged.number, e));
+    }
+
+    if config::log_enabled(config::LogLevel::Info) {
+        println!(
+            "📝  #{} : {} of {} sampled cells verified in {} ms, confidence {:.4} %",
+            merged.number,
+            merged.verified(),
+            merged.cells.len(),
+            merged.micros / 1000,
+            merged.confidence
+        );
+    }
+    if config::log_enabled(config::LogLevel::Debug) {
+        println!("📝  {}", merged.to_json());
+    }
+
+    merged
+}
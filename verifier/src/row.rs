@@ -0,0 +1,87 @@
+use std::convert::TryInto;
+
+use dusk_plonk::bls12_381::G1Affine;
+use dusk_plonk::fft::{EvaluationDomain, Polynomial};
+use dusk_plonk::prelude::BlsScalar;
+
+use crate::{backend, ethereum, params};
+
+// Parses 32 bytes chunks as scalars, failing if any of
+// them is not canonically encoded
+pub fn parse_scalars(bytes: &[u8]) -> Result<Vec<BlsScalar>, String> {
+    if bytes.len() % 32 != 0 {
+        return Err(format!("evaluations of length {} not multiple of 32", bytes.len()));
+    }
+
+    bytes
+        .chunks(32)
+        .enumerate()
+        .map(|(i, chunk)| {
+            Option::from(BlsScalar::from_bytes(chunk.try_into().unwrap()))
+                .ok_or_else(|| format!("evaluation {} is not a valid scalar", i))
+        })
+        .collect()
+}
+
+// Parses 48 bytes compressed commitment of one row
+pub fn parse_commitment(commitment: &[u8]) -> Result<G1Affine, String> {
+    let commitment: [u8; 48] = commitment
+        .try_into()
+        .map_err(|_| format!("commitment of length {}, expected 48", commitment.len()))?;
+
+    Option::from(G1Affine::from_compressed(&commitment)).ok_or_else(|| "commitment is not a valid point".to_string())
+}
+
+// Rows wider than header can describe are rejected, before evaluation
+// domain of such size is attempted to be built
+pub fn check_cols(total_cols: usize) -> Result<(), String> {
+    if total_cols == 0 || total_cols > u16::MAX as usize {
+        return Err(format!("invalid number of columns {}", total_cols));
+    }
+
+    Ok(())
+}
+
+// Interpolates row polynomial from its evaluations over row evaluation domain
+// & commits to it, using same public parameters as of cell verification
+pub fn commit(evals: &[BlsScalar], total_cols: usize) -> Result<G1Affine, String> {
+    check_cols(total_cols)?;
+    let row_eval_domain = EvaluationDomain::new(total_cols).map_err(|e| format!("{:?}", e))?;
+    if row_eval_domain.size() != evals.len() {
+        return Err(format!(
+            "expected {} evaluations, found {}",
+            row_eval_domain.size(),
+            evals.len()
+        ));
+    }
+
+    let (commit_key, _) = params::PUBLIC_PARAMS.trim(total_cols).map_err(|e| format!("{:?}", e))?;
+    let poly = Polynomial::from_coefficients_vec(row_eval_domain.ifft(evals));
+
+    commit_key
+        .commit(&poly)
+        .map(|c| c.0)
+        .map_err(|e| format!("{:?}", e))
+}
+
+// Verifies whole row of data matrix, given its 48 bytes commitment ( sliced
+// out of header commitment ) & 32 bytes evaluation of each cell of that row
+pub fn verify_row(commitment: &[u8], evals: &[u8], total_cols: usize) -> Result<bool, String> {
+    params::require_dusk("whole rows are")?;
+
+    let commitment_point = parse_commitment(commitment)?;
+    let evals = parse_scalars(evals)?;
+    Ok(commit(&evals, total_cols)? == commitment_point)
+}
+
+// Checks 80 bytes opening ( witness + evaluation ) of cell at column `col`
+// against 48 bytes commitment of its row, failing on malformed input
+// instead of panicking, as opening may come from untrusted peer
+//
+// Opening is expected in encoding of configured SRS format
+pub fn verify_opening(commitment: &[u8], opening: &[u8], col: usize, total_cols: usize) -> Result<bool, String> {
+    match *params::FORMAT {
+        params::SrsFormat::Dusk => backend::get().verify_opening(commitment, opening, col, total_cols),
+        params::SrsFormat::Ethereum => ethereum::verify_opening(commitment, opening, col, total_cols),
+    }
+}
@@ -0,0 +1,85 @@
+// Picks cells of block to be sampled, uniformly at random & without
+// replacement, out of whole extended matrix i.e. `total_rows * 2` rows, as
+// confidence reported for block assumes so
+//
+// Light client asks for sample of each round through C ABI & data withholding
+// simulation draws its samples from here too, so that both agree
+
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use blake2_rfc::blake2b::blake2b;
+
+// Deterministic pseudo random numbers, expanded from given seed, so that
+// simulations can be rerun
+pub struct Rng {
+    seed: u64,
+    counter: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { seed, counter: 0 }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut input = self.seed.to_le_bytes().to_vec();
+        input.extend_from_slice(&self.counter.to_le_bytes());
+        self.counter += 1;
+
+        u64::from_le_bytes(blake2b(8, &[], &input).as_bytes().try_into().unwrap())
+    }
+
+    // `count` distinct indices out of `0..n`, using partial Fisher-Yates shuffle
+    pub fn choose(&mut self, n: usize, count: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..n).collect();
+        let count = count.min(n);
+
+        for i in 0..count {
+            let j = i + (self.next_u64() % (n - i) as u64) as usize;
+            indices.swap(i, j);
+        }
+
+        indices.truncate(count);
+        indices
+    }
+}
+
+// `count` cells of extended matrix, as (row, col), none of which is in
+// `sampled`, fewer if not that many are left
+pub fn sample_cells(
+    total_rows: usize,
+    total_cols: usize,
+    sampled: &HashSet<(u16, u16)>,
+    count: usize,
+    rng: &mut Rng,
+) -> Vec<(u16, u16)> {
+    let extended_rows = total_rows * 2;
+    let total = extended_rows * total_cols;
+    let cell_of = |i: usize| ((i / total_cols) as u16, (i % total_cols) as u16);
+
+    let known = sampled
+        .iter()
+        .filter(|(row, col)| (*row as usize) < extended_rows && (*col as usize) < total_cols)
+        .count();
+    let count = count.min(total - known);
+
+    // when most of cells are still left, random picks rarely collide, so
+    // whole matrix needn't be enumerated
+    if (total - known) / 2 >= count {
+        let mut picked = HashSet::new();
+        let mut cells = Vec::with_capacity(count);
+
+        while cells.len() < count {
+            let cell = cell_of((rng.next_u64() % total as u64) as usize);
+            if !sampled.contains(&cell) && picked.insert(cell) {
+                cells.push(cell);
+            }
+        }
+
+        return cells;
+    }
+
+    let left: Vec<(u16, u16)> = (0..total).map(cell_of).filter(|cell| !sampled.contains(cell)).collect();
+    rng.choose(left.len(), count).into_iter().map(|i| left[i]).collect()
+}
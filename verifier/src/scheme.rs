@@ -0,0 +1,46 @@
+// Commitment scheme of data matrix, deciding which commitment each cell
+// is verified against & at which domain point it is opened
+//
+// Currently chain commits to each extended row, while 2D schemes also
+// commit to each column, so that a cell can be checked against either
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    // one commitment per extended row, cell at column `c` is opened at
+    // `c`-th point of row domain of `total_cols` elements
+    Row,
+    // one commitment per column, cell at extended row `r` is opened at
+    // `r`-th point of column domain of `total_rows * 2` elements
+    Column,
+}
+
+impl Scheme {
+    // Scheme as passed over C ABI, 0 for row & 1 for column commitments
+    pub fn from_u8(v: u8) -> Option<Scheme> {
+        match v {
+            0 => Some(Scheme::Row),
+            1 => Some(Scheme::Column),
+            _ => None,
+        }
+    }
+
+    // Index of commitment, cell at extended row `row` & column `col` is
+    // verified against
+    pub fn commitment_of(self, row: usize, col: usize) -> usize {
+        match self {
+            Scheme::Row => row,
+            Scheme::Column => col,
+        }
+    }
+
+    // Index of domain point, cell is opened at, along with domain size
+    pub fn opening_point(self, row: usize, col: usize, total_rows: usize, total_cols: usize) -> (usize, usize) {
+        match self {
+            Scheme::Row => (col, total_cols),
+            Scheme::Column => (row, total_rows * 2),
+        }
+    }
+}
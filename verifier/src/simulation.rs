@@ -0,0 +1,180 @@
+// Data withholding simulation, checking whether confidence light client
+// reports actually matches how often withholding gets detected
+//
+// For each trial, some fraction of extended cells of block is withheld i.e.
+// proofs of those are never served, light client samples random cells, through
+// same sampler it uses for real blocks & verifies served proofs. Block is
+// considered detected as unavailable, when any sampled cell couldn't be verified
+//
+// @note Only compiled with `test-support` feature, as blocks are built using
+// test-only prover
+
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::report;
+use crate::sampling::{sample_cells, Rng};
+use crate::scheme::Scheme;
+use crate::testing::Fixture;
+use crate::verify_cells;
+
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    // dimensions of original data matrix, extended to twice as many rows
+    pub rows: usize,
+    pub cols: usize,
+    // fraction of extended cells withheld, in [0, 1]
+    pub withheld_fraction: f64,
+    // cells sampled per block, same as `AskProofCount`
+    pub sample_count: usize,
+    pub trials: usize,
+    // distinct blocks trials are spread over, proofs are built once per block
+    pub blocks: usize,
+    pub seed: u64,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        SimulationConfig {
+            rows: 8,
+            cols: 16,
+            withheld_fraction: 0.5,
+            sample_count: 15,
+            trials: 1000,
+            blocks: 4,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub trials: usize,
+    pub withheld_fraction: f64,
+    pub withheld_cells: usize,
+    pub total_cells: usize,
+    pub sample_count: usize,
+    // trials, in which withholding was detected
+    pub detected: usize,
+    pub detection_rate: f64,
+    // as reported by light client, when all sampled cells verify,
+    // out of 100
+    pub computed_confidence: f64,
+    // exact probability of sampling at least one withheld cell
+    pub expected_detection_rate: f64,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "withheld {:>5.1} % ( {:>4} / {:>4} ) | samples {:>3} | trials {:>6} | detected {:>7.3} % | expected {:>7.3} % | confidence {:>7.3} %",
+            self.withheld_fraction * 100.0,
+            self.withheld_cells,
+            self.total_cells,
+            self.sample_count,
+            self.trials,
+            self.detection_rate * 100.0,
+            self.expected_detection_rate * 100.0,
+            self.computed_confidence,
+        )
+    }
+}
+
+// Same formula light client uses for confidence, out of 100, given
+// `verified` distinct cells of extended matrix
+pub fn computed_confidence(rows: usize, cols: usize, verified: usize) -> f64 {
+    report::confidence(rows, cols, verified)
+}
+
+// Probability of sampling, without replacement, at least one of
+// `withheld` cells, out of `total`
+pub fn expected_detection_rate(total: usize, withheld: usize, samples: usize) -> f64 {
+    let missed = (0..samples.min(total)).fold(1.0, |p, i| p * total.saturating_sub(withheld + i) as f64 / (total - i) as f64);
+
+    1.0 - missed
+}
+
+// Runs simulation, as per given configuration
+pub fn run(config: &SimulationConfig) -> Result<Report, String> {
+    if !(0.0..=1.0).contains(&config.withheld_fraction) {
+        return Err(format!("withheld fraction must be in [0, 1], found {}", config.withheld_fraction));
+    }
+
+    if config.sample_count == 0 || config.sample_count > u8::MAX as usize {
+        return Err(format!("sample count must be in [1, {}], found {}", u8::MAX, config.sample_count));
+    }
+
+    if config.trials == 0 || config.blocks == 0 {
+        return Err("at least one trial & block is required".to_string());
+    }
+
+    let extended_rows = config.rows * 2;
+    if extended_rows > u16::MAX as usize + 1 || config.cols > u16::MAX as usize + 1 {
+        return Err(format!("{} x {} matrix doesn't fit verifier's cell indices", extended_rows, config.cols));
+    }
+
+    let total = extended_rows * config.cols;
+    let withheld_count = (config.withheld_fraction * total as f64).round() as usize;
+
+    let mut rng = Rng::new(config.seed);
+
+    // proofs of all cells, for each block, built once
+    let blocks: Vec<(Fixture, Vec<u8>)> = (0..config.blocks)
+        .map(|i| {
+            let fixture = Fixture::random(config.rows, config.cols, config.seed ^ i as u64);
+            let cells: Vec<(usize, usize)> = (0..total).map(|i| (i / config.cols, i % config.cols)).collect();
+            let proofs = fixture.proofs(&cells);
+            (fixture, proofs)
+        })
+        .collect();
+
+    let mut detected = 0;
+    for trial in 0..config.trials {
+        let (fixture, proofs) = &blocks[trial % blocks.len()];
+
+        let withheld: HashSet<usize> = rng.choose(total, withheld_count).into_iter().collect();
+        let sampled: Vec<usize> = sample_cells(config.rows, config.cols, &HashSet::new(), config.sample_count, &mut rng)
+            .into_iter()
+            .map(|(row, col)| row as usize * config.cols + col as usize)
+            .collect();
+
+        // only served proofs are verified, withheld ones count as failed
+        let served: Vec<usize> = sampled.iter().cloned().filter(|i| !withheld.contains(i)).collect();
+        let rows: Vec<u16> = served.iter().map(|i| (i / config.cols) as u16).collect();
+        let cols: Vec<u16> = served.iter().map(|i| (i % config.cols) as u16).collect();
+        let proof: Vec<u8> = served.iter().flat_map(|i| proofs[i * 80..i * 80 + 80].iter().cloned()).collect();
+
+        let verified = verify_cells(
+            Scheme::Row,
+            trial as u64,
+            extended_rows,
+            config.cols,
+            &rows,
+            &cols,
+            &fixture.commitment,
+            &proof,
+        )?
+        .iter()
+        .filter(|c| c.verified)
+        .count();
+
+        if verified < sampled.len() {
+            detected += 1;
+        }
+    }
+
+    Ok(Report {
+        trials: config.trials,
+        withheld_fraction: config.withheld_fraction,
+        withheld_cells: withheld_count,
+        total_cells: total,
+        sample_count: config.sample_count,
+        detected,
+        detection_rate: detected as f64 / config.trials as f64,
+        computed_confidence: computed_confidence(config.rows, config.cols, config.sample_count.min(total)),
+        expected_detection_rate: expected_detection_rate(total, withheld_count, config.sample_count),
+    })
+}
@@ -0,0 +1,290 @@
+// Test support, builds data matrices along with commitments & cell proofs,
+// in exact format full node serves them, so that verifier can be exercised
+// offline, without running chain
+//
+// @note Only compiled for tests or with `test-support` feature, never
+// meant to be used by light client itself
+
+use std::convert::TryInto;
+
+use blake2_rfc::blake2b::blake2b;
+use dusk_plonk::commitment_scheme::kzg10::{CommitKey, PublicParameters};
+use dusk_plonk::fft::{EvaluationDomain, Polynomial};
+use dusk_plonk::prelude::BlsScalar;
+
+use crate::reconstruction::vanishing_polynomial;
+use crate::{multiproof, params};
+
+// Public parameters verifier itself uses, as per configuration,
+// embedded ones unless some SRS file is configured
+pub fn public_params() -> &'static PublicParameters {
+    &params::PUBLIC_PARAMS
+}
+
+// Loading of public parameters & opening check of single cell, as done
+// by verifier internally, for benchmarking those in isolation
+pub use crate::params::{load as load_public_params, SrsFormat};
+pub use crate::row::verify_opening;
+
+// Deterministic pseudo random scalars, derived from seed
+pub fn random_scalars(seed: u64, count: usize) -> Vec<BlsScalar> {
+    (0..count as u64)
+        .map(|i| {
+            let mut input = seed.to_le_bytes().to_vec();
+            input.extend_from_slice(&i.to_le_bytes());
+
+            let wide: [u8; 64] = blake2b(64, &[], &input).as_bytes().try_into().unwrap();
+            BlsScalar::from_bytes_wide(&wide)
+        })
+        .collect()
+}
+
+// Divides p(x) - p(z) by (x - z), using synthetic division, leaving
+// quotient, which is committed to as opening witness
+fn witness_polynomial(coeffs: &[BlsScalar], z: BlsScalar) -> Vec<BlsScalar> {
+    let mut quotient = vec![BlsScalar::zero(); coeffs.len().saturating_sub(1)];
+    let mut carry = BlsScalar::zero();
+
+    for i in (1..coeffs.len()).rev() {
+        carry = coeffs[i] + carry * z;
+        quotient[i - 1] = carry;
+    }
+
+    quotient
+}
+
+// Divides polynomial by monic one, returning quotient & remainder
+fn divide(num: &[BlsScalar], den: &[BlsScalar]) -> (Vec<BlsScalar>, Vec<BlsScalar>) {
+    let degree = den.len() - 1;
+    let mut rem = num.to_vec();
+    if rem.len() <= degree {
+        return (Vec::new(), rem);
+    }
+
+    let mut quotient = vec![BlsScalar::zero(); rem.len() - degree];
+    for i in (0..quotient.len()).rev() {
+        let coeff = rem[i + degree];
+        quotient[i] = coeff;
+
+        for (j, c) in den.iter().enumerate() {
+            rem[i + j] -= coeff * c;
+        }
+    }
+
+    rem.truncate(degree);
+    (quotient, rem)
+}
+
+// Evaluates polynomial at `z`, using Horner's method
+fn evaluate(coeffs: &[BlsScalar], z: BlsScalar) -> BlsScalar {
+    coeffs.iter().rev().fold(BlsScalar::zero(), |acc, c| acc * z + c)
+}
+
+// Extended data matrix of `total_rows * 2` rows, with commitment of each row
+//
+// Each column of original data is erasure coded by evaluating its polynomial
+// ( of degree < `total_rows` ) over twice as large domain, so original row
+// `r` ends up being extended row `2 * r` & odd rows carry parity
+pub struct Fixture {
+    pub total_rows: usize,
+    pub total_cols: usize,
+    // extended matrix, in row major order
+    pub matrix: Vec<Vec<BlsScalar>>,
+    // 48 bytes compressed commitment of each extended row, concatenated,
+    // same as carried in header
+    pub commitment: Vec<u8>,
+    // 48 bytes compressed commitment of each extended column, as of 2D
+    // schemes, where columns are committed to too
+    pub column_commitment: Vec<u8>,
+    commit_key: CommitKey,
+    row_polys: Vec<Vec<BlsScalar>>,
+    row_points: Vec<BlsScalar>,
+    column_commit_key: CommitKey,
+    column_polys: Vec<Vec<BlsScalar>>,
+    column_points: Vec<BlsScalar>,
+}
+
+// Commits to polynomial, given its coefficients
+fn commit(commit_key: &CommitKey, coeffs: &[BlsScalar]) -> Result<[u8; 48], String> {
+    commit_key
+        .commit(&Polynomial::from_coefficients_vec(coeffs.to_vec()))
+        .map(|c| c.0.to_compressed())
+        .map_err(|e| format!("{:?}", e))
+}
+
+// 80 bytes opening of polynomial at `z` i.e. 48 bytes compressed witness
+// followed by 32 bytes evaluation
+fn open(commit_key: &CommitKey, coeffs: &[BlsScalar], z: BlsScalar, eval: BlsScalar) -> Vec<u8> {
+    let quotient = witness_polynomial(coeffs, z);
+    let witness = commit(commit_key, &quotient).expect("witness polynomial within committable degree");
+
+    let mut proof = witness.to_vec();
+    proof.extend_from_slice(&eval.to_bytes());
+    proof
+}
+
+impl Fixture {
+    // Given original data matrix, as `total_rows * total_cols` scalars in row
+    // major order, extends it & commits to each extended row
+    pub fn new(pp: &PublicParameters, total_rows: usize, total_cols: usize, data: &[BlsScalar]) -> Result<Fixture, String> {
+        if data.len() != total_rows * total_cols {
+            return Err(format!("expected {} x {} cells, found {}", total_rows, total_cols, data.len()));
+        }
+
+        let col_eval_domain = EvaluationDomain::new(total_rows).map_err(|e| format!("{:?}", e))?;
+        let ext_eval_domain = EvaluationDomain::new(total_rows * 2).map_err(|e| format!("{:?}", e))?;
+        if col_eval_domain.size() != total_rows {
+            return Err(format!("{} rows is not power of 2", total_rows));
+        }
+
+        let mut matrix = vec![vec![BlsScalar::zero(); total_cols]; total_rows * 2];
+        for col in 0..total_cols {
+            let column: Vec<BlsScalar> = (0..total_rows).map(|row| data[row * total_cols + col]).collect();
+            let mut coeffs = col_eval_domain.ifft(&column);
+            coeffs.resize(total_rows * 2, BlsScalar::zero());
+
+            for (row, eval) in ext_eval_domain.fft(&coeffs).into_iter().enumerate() {
+                matrix[row][col] = eval;
+            }
+        }
+
+        Fixture::from_extended(pp, total_rows, total_cols, matrix)
+    }
+
+    // Commits to each row & column of given extended matrix, as is, without checking
+    // its columns are correctly erasure coded, so that incorrectly encoded
+    // blocks can be built too
+    pub fn from_extended(
+        pp: &PublicParameters,
+        total_rows: usize,
+        total_cols: usize,
+        matrix: Vec<Vec<BlsScalar>>,
+    ) -> Result<Fixture, String> {
+        if matrix.len() != total_rows * 2 || matrix.iter().any(|row| row.len() != total_cols) {
+            return Err(format!("expected {} x {} extended cells", total_rows * 2, total_cols));
+        }
+
+        let row_eval_domain = EvaluationDomain::new(total_cols).map_err(|e| format!("{:?}", e))?;
+        if row_eval_domain.size() != total_cols {
+            return Err(format!("{} columns is not power of 2", total_cols));
+        }
+
+        let (commit_key, _) = pp.trim(total_cols).map_err(|e| format!("{:?}", e))?;
+
+        let mut commitment = Vec::with_capacity(matrix.len() * 48);
+        let mut row_polys = Vec::with_capacity(matrix.len());
+        for row in matrix.iter() {
+            let coeffs = row_eval_domain.ifft(row);
+            commitment.extend_from_slice(&commit(&commit_key, &coeffs)?);
+            row_polys.push(coeffs);
+        }
+
+        let col_eval_domain = EvaluationDomain::new(total_rows * 2).map_err(|e| format!("{:?}", e))?;
+        if col_eval_domain.size() != total_rows * 2 {
+            return Err(format!("{} rows is not power of 2", total_rows));
+        }
+
+        let (column_commit_key, _) = pp.trim(total_rows * 2).map_err(|e| format!("{:?}", e))?;
+
+        let mut column_commitment = Vec::with_capacity(total_cols * 48);
+        let mut column_polys = Vec::with_capacity(total_cols);
+        for col in 0..total_cols {
+            let column: Vec<BlsScalar> = matrix.iter().map(|row| row[col]).collect();
+            let coeffs = col_eval_domain.ifft(&column);
+            column_commitment.extend_from_slice(&commit(&column_commit_key, &coeffs)?);
+            column_polys.push(coeffs);
+        }
+
+        Ok(Fixture {
+            total_rows,
+            total_cols,
+            matrix,
+            commitment,
+            column_commitment,
+            commit_key,
+            row_polys,
+            row_points: row_eval_domain.elements().collect(),
+            column_commit_key,
+            column_polys,
+            column_points: col_eval_domain.elements().collect(),
+        })
+    }
+
+    // Fixture of pseudo random data, same seed always gives same matrix
+    pub fn random(total_rows: usize, total_cols: usize, seed: u64) -> Fixture {
+        let data = random_scalars(seed, total_rows * total_cols);
+        Fixture::new(public_params(), total_rows, total_cols, &data).expect("valid fixture dimensions")
+    }
+
+    // 32 bytes data chunk of cell of extended matrix
+    pub fn cell_data(&self, row: usize, col: usize) -> [u8; 32] {
+        self.matrix[row][col].to_bytes()
+    }
+
+    // 48 bytes commitment of extended row
+    pub fn row_commitment(&self, row: usize) -> &[u8] {
+        &self.commitment[row * 48..(row + 1) * 48]
+    }
+
+    // 48 bytes commitment of extended column
+    pub fn column_commitment(&self, col: usize) -> &[u8] {
+        &self.column_commitment[col * 48..(col + 1) * 48]
+    }
+
+    // 80 bytes proof of cell i.e. 48 bytes compressed witness
+    // followed by 32 bytes evaluation
+    pub fn proof(&self, row: usize, col: usize) -> Vec<u8> {
+        open(&self.commit_key, &self.row_polys[row], self.row_points[col], self.matrix[row][col])
+    }
+
+    // Proofs of given cells, concatenated in order, as returned by `kate_queryProof`
+    // & consumed by `verify_proof`
+    pub fn proofs(&self, cells: &[(usize, usize)]) -> Vec<u8> {
+        cells.iter().flat_map(|&(row, col)| self.proof(row, col)).collect()
+    }
+
+    // 80 bytes proof of cell, against commitment of its column, opened
+    // at `row`-th point of column domain
+    pub fn column_proof(&self, row: usize, col: usize) -> Vec<u8> {
+        open(
+            &self.column_commit_key,
+            &self.column_polys[col],
+            self.column_points[row],
+            self.matrix[row][col],
+        )
+    }
+
+    // Multiproof of cells of extended row, at given columns, as returned by
+    // `kate_queryMultiProof` & consumed by `verify_multiproof`
+    pub fn multiproof(&self, row: usize, cols: &[usize]) -> Vec<u8> {
+        let poly = &self.row_polys[row];
+        let points: Vec<BlsScalar> = cols.iter().map(|&col| self.row_points[col]).collect();
+
+        // remainder is polynomial interpolating opened cells
+        let (quotient, interpolated) = divide(poly, &vanishing_polynomial(&points));
+        let quotient_witness = commit(&self.commit_key, &quotient).expect("quotient within committable degree");
+
+        let evals: Vec<u8> = cols.iter().flat_map(|&col| self.cell_data(row, col).to_vec()).collect();
+        let z = multiproof::challenge(self.row_commitment(row), cols, &evals, &quotient_witness);
+
+        // combined polynomial, vanishing at `z`
+        let vanishing = multiproof::vanishing_at(&points, z);
+        let mut combined = poly.clone();
+        for (i, c) in quotient.iter().enumerate() {
+            combined[i] -= vanishing * c;
+        }
+        combined[0] -= evaluate(&interpolated, z);
+
+        let witness = commit(&self.commit_key, &witness_polynomial(&combined, z)).expect("witness within committable degree");
+
+        let mut proof = quotient_witness.to_vec();
+        proof.extend_from_slice(&witness);
+        proof.extend_from_slice(&evals);
+        proof
+    }
+
+    // Column proofs of given cells, concatenated in order
+    pub fn column_proofs(&self, cells: &[(usize, usize)]) -> Vec<u8> {
+        cells.iter().flat_map(|&(row, col)| self.column_proof(row, col)).collect()
+    }
+}
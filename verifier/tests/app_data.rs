@@ -0,0 +1,155 @@
+use std::ffi::CStr;
+
+use codec::{Compact, Encode};
+use dusk_plonk::prelude::BlsScalar;
+
+use verifier::testing::{public_params, random_scalars, Fixture};
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+// SCALE encoded header of block with given data matrix, where application
+// data index is given as (app id, start cell) pairs
+fn header(fixture: &Fixture, size: u32, index: &[(u32, u32)]) -> Vec<u8> {
+    let mut encoded = [0u8; 32].encode();
+    Compact(1u32).encode_to(&mut encoded);
+    [0u8; 32].encode_to(&mut encoded);
+    [0u8; 32].encode_to(&mut encoded);
+    fixture.commitment.encode_to(&mut encoded);
+    (fixture.total_rows as u16).encode_to(&mut encoded);
+    (fixture.total_cols as u16).encode_to(&mut encoded);
+    // no digest logs
+    Compact(0u32).encode_to(&mut encoded);
+    Compact(size).encode_to(&mut encoded);
+    Compact(index.len() as u32).encode_to(&mut encoded);
+    for (app_id, start) in index {
+        Compact(*app_id).encode_to(&mut encoded);
+        Compact(*start).encode_to(&mut encoded);
+    }
+    encoded
+}
+
+// Application data, as laid out by full node i.e. SCALE encoded list of
+// extrinsics, padded with `0x80` & zeros, in 31 bytes chunks
+fn chunks_of(extrinsics: &[Vec<u8>]) -> Vec<BlsScalar> {
+    let mut data = extrinsics.to_vec().encode();
+    data.push(0x80);
+    while data.len() % 31 != 0 {
+        data.push(0);
+    }
+
+    data.chunks(31)
+        .map(|chunk| {
+            let mut bytes = [0u8; 32];
+            bytes[..31].copy_from_slice(chunk);
+            BlsScalar::from_bytes(&bytes).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn cells_of_application_are_found_in_extended_matrix() {
+    let extrinsics = vec![vec![1u8; 40], vec![2u8; 150], vec![3u8; 60]];
+    let chunks = chunks_of(&extrinsics);
+    // spans over two original rows
+    assert!(chunks.len() > COLS);
+
+    // application 1 comes after 3 cells of application 0
+    let mut data = random_scalars(5, ROWS * COLS);
+    data[3..3 + chunks.len()].copy_from_slice(&chunks);
+    let fixture = Fixture::new(public_params(), ROWS, COLS, &data).unwrap();
+
+    let size = (3 + chunks.len()) as u32;
+    let encoded = header(&fixture, size, &[(0, 0), (1, 3)]);
+
+    let count = verifier::app_cells(
+        encoded.as_ptr(),
+        encoded.len(),
+        1,
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        0,
+    );
+    assert_eq!(count, chunks.len() as i64);
+
+    let mut rows = vec![0u16; count as usize];
+    let mut cols = vec![0u16; count as usize];
+    verifier::app_cells(encoded.as_ptr(), encoded.len(), 1, rows.as_mut_ptr(), cols.as_mut_ptr(), rows.len());
+
+    // original rows are even rows of extended matrix
+    assert_eq!((rows[0], cols[0]), (0, 3));
+    assert_eq!((rows[COLS - 3], cols[COLS - 3]), (2, 0));
+
+    let mut cells = Vec::new();
+    for (row, col) in rows.iter().zip(cols.iter()) {
+        cells.extend_from_slice(&fixture.cell_data(*row as usize, *col as usize));
+    }
+
+    let resp = verifier::decode_app_data(cells.as_ptr(), cells.len());
+    let json: serde_json::Value = serde_json::from_str(unsafe { CStr::from_ptr(resp) }.to_str().unwrap()).unwrap();
+    verifier::free_string(resp);
+
+    let expected: Vec<String> = extrinsics.iter().map(|e| format!("0x{}", hex::encode(e))).collect();
+    assert_eq!(json["extrinsics"], serde_json::json!(expected), "{}", json);
+}
+
+#[test]
+fn cells_beyond_original_matrix_are_left_out() {
+    let fixture = Fixture::random(ROWS, COLS, 6);
+    // index claims more cells than original matrix has
+    let encoded = header(&fixture, (ROWS * COLS * 2) as u32, &[(0, 0), (1, (ROWS * COLS - 2) as u32)]);
+
+    let mut rows = vec![0u16; 4];
+    let mut cols = vec![0u16; 4];
+    let count = verifier::app_cells(encoded.as_ptr(), encoded.len(), 1, rows.as_mut_ptr(), cols.as_mut_ptr(), 4);
+
+    assert_eq!(count, 2);
+    assert_eq!((rows[0], cols[0]), ((ROWS - 1) as u16 * 2, (COLS - 2) as u16));
+    assert_eq!((rows[1], cols[1]), ((ROWS - 1) as u16 * 2, (COLS - 1) as u16));
+}
+
+#[test]
+fn cells_beyond_first_256_rows_are_verified() {
+    let dir = std::env::temp_dir().join(format!("verifier-app-data-{}", std::process::id()));
+    let args = std::ffi::CString::new(serde_json::json!(["--storage-path", dir.to_str().unwrap()]).to_string()).unwrap();
+    verifier::free_string(verifier::load_config(args.as_ptr()));
+
+    // application data starts at last original row, i.e. extended row 510
+    let (rows, cols) = (256, 2);
+    let fixture = Fixture::random(rows, cols, 8);
+    let encoded = header(&fixture, (rows * cols) as u32, &[(0, 0), (1, ((rows - 1) * cols) as u32)]);
+
+    let mut app_rows = vec![0u16; cols];
+    let mut app_cols = vec![0u16; cols];
+    let count = verifier::app_cells(encoded.as_ptr(), encoded.len(), 1, app_rows.as_mut_ptr(), app_cols.as_mut_ptr(), cols);
+    assert_eq!(count, cols as i64);
+    assert_eq!((app_rows[1], app_cols[1]), (510, 1));
+
+    let cells: Vec<(usize, usize)> = app_rows.iter().zip(app_cols.iter()).map(|(&r, &c)| (r as usize, c as usize)).collect();
+    let proof = fixture.proofs(&cells);
+    let mut out = vec![0u8; cells.len() * 33];
+    let mut confidence = -1f64;
+
+    let verified = verifier::verify_proof_with_data(
+        1,
+        [9u8; 32].as_ptr(),
+        32,
+        rows,
+        cols,
+        app_rows.as_ptr(),
+        app_rows.len(),
+        app_cols.as_ptr(),
+        app_cols.len(),
+        fixture.commitment.as_ptr(),
+        fixture.commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+        out.as_mut_ptr(),
+        out.len(),
+        &mut confidence,
+    );
+    assert_eq!(verified as usize, cells.len());
+    assert_eq!(&out[34..], &fixture.cell_data(510, 1)[..]);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,95 @@
+use dusk_plonk::prelude::BlsScalar;
+
+use verifier::backend::{BlstVerifier, CellVerifier, DuskVerifier};
+use verifier::testing::{public_params, Fixture};
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+fn backends() -> (DuskVerifier, BlstVerifier) {
+    (DuskVerifier, BlstVerifier::from_params(public_params()).unwrap())
+}
+
+// Both backends must reach same verdict, erroring on same inputs
+fn assert_agree(commitment: &[u8], opening: &[u8], point: usize, domain_size: usize) -> Option<bool> {
+    let (dusk, blst) = backends();
+    let a = dusk.verify_opening(commitment, opening, point, domain_size);
+    let b = blst.verify_opening(commitment, opening, point, domain_size);
+
+    assert_eq!(a.is_err(), b.is_err(), "{:?} vs {:?}, for point {} of {}", a, b, point, domain_size);
+    assert_eq!(a.as_ref().ok(), b.as_ref().ok(), "verdict for point {} of {}", point, domain_size);
+    a.ok()
+}
+
+#[test]
+fn backends_agree_on_row_openings() {
+    let fixture = Fixture::random(ROWS, COLS, 11);
+
+    for row in 0..ROWS * 2 {
+        for col in 0..COLS {
+            let proof = fixture.proof(row, col);
+            assert_eq!(assert_agree(fixture.row_commitment(row), &proof, col, COLS), Some(true));
+
+            // opened at some other point
+            assert_eq!(assert_agree(fixture.row_commitment(row), &proof, (col + 1) % COLS, COLS), Some(false));
+        }
+    }
+}
+
+#[test]
+fn backends_agree_on_column_openings() {
+    let fixture = Fixture::random(ROWS, COLS, 12);
+
+    for col in 0..COLS {
+        for row in 0..ROWS * 2 {
+            let proof = fixture.column_proof(row, col);
+            assert_eq!(assert_agree(fixture.column_commitment(col), &proof, row, ROWS * 2), Some(true));
+        }
+    }
+}
+
+#[test]
+fn backends_agree_on_tampered_openings() {
+    let fixture = Fixture::random(ROWS, COLS, 13);
+    let other = Fixture::random(ROWS, COLS, 14);
+    let proof = fixture.proof(3, 5);
+
+    // evaluation swapped with some other value
+    let mut tampered = proof.clone();
+    tampered[48..].copy_from_slice(&BlsScalar::from(42).to_bytes());
+    assert_eq!(assert_agree(fixture.row_commitment(3), &tampered, 5, COLS), Some(false));
+
+    // witness of some other cell
+    let mut tampered = proof.clone();
+    tampered[..48].copy_from_slice(&fixture.proof(3, 4)[..48]);
+    assert_eq!(assert_agree(fixture.row_commitment(3), &tampered, 5, COLS), Some(false));
+
+    // commitment of some other block
+    assert_eq!(assert_agree(other.row_commitment(3), &proof, 5, COLS), Some(false));
+}
+
+#[test]
+fn backends_agree_on_malformed_openings() {
+    let fixture = Fixture::random(ROWS, COLS, 15);
+    let commitment = fixture.row_commitment(0);
+    let proof = fixture.proof(0, 1);
+
+    // truncated opening & commitment
+    assert_eq!(assert_agree(commitment, &proof[..79], 1, COLS), None);
+    assert_eq!(assert_agree(&commitment[..47], &proof, 1, COLS), None);
+
+    // witness not on curve
+    let mut bad = proof.clone();
+    bad[..48].copy_from_slice(&[0xffu8; 48]);
+    assert_eq!(assert_agree(commitment, &bad, 1, COLS), None);
+
+    // evaluation not canonically encoded
+    let mut bad = proof.clone();
+    bad[48..].copy_from_slice(&[0xffu8; 32]);
+    assert_eq!(assert_agree(commitment, &bad, 1, COLS), None);
+
+    // point out of domain, empty domain & domain wider than header can describe
+    assert_eq!(assert_agree(commitment, &proof, COLS, COLS), None);
+    assert_eq!(assert_agree(commitment, &proof, 0, 0), None);
+    assert_eq!(assert_agree(commitment, &proof, 0, u16::MAX as usize + 1), None);
+}
@@ -0,0 +1,114 @@
+use verifier::chain::{ExtrinsicsRoot, Hash, Header, HeaderChain, Import, MAX_HEADERS};
+
+// Hash of block at given height, on given branch
+fn hash_of(number: u64, branch: u8) -> Hash {
+    let mut hash = [branch; 32];
+    hash[..8].copy_from_slice(&number.to_le_bytes());
+    hash
+}
+
+fn header(number: u64, branch: u8, parent_branch: u8) -> Header {
+    Header {
+        hash: hash_of(number, branch),
+        parent_hash: hash_of(number.wrapping_sub(1), parent_branch),
+        number,
+        state_root: [0u8; 32],
+        extrinsics_root: ExtrinsicsRoot {
+            hash: [0u8; 32],
+            commitment: vec![],
+            rows: 0,
+            cols: 0,
+        },
+        logs: vec![],
+        app_data_lookup: Default::default(),
+    }
+}
+
+// Imports blocks of given range, all on same branch
+fn extend(chain: &mut HeaderChain, numbers: std::ops::RangeInclusive<u64>, branch: u8) {
+    for number in numbers {
+        assert_eq!(chain.import(header(number, branch, branch)), Import::Extended, "#{}", number);
+    }
+}
+
+#[test]
+fn linear_chain_is_imported() {
+    let mut chain = HeaderChain::new();
+    extend(&mut chain, 10..=20, 0);
+
+    assert_eq!(chain.head().unwrap().number, 20);
+    assert_eq!(chain.import(header(15, 0, 0)), Import::Known);
+    assert_eq!(chain.import(header(22, 0, 0)), Import::UnknownParent);
+    assert_eq!(chain.import(header(22, 1, 0)), Import::UnknownParent);
+
+    let mut bad = header(21, 0, 0);
+    bad.number = 22;
+    assert_eq!(chain.import(bad), Import::BadNumber);
+
+    for number in 10..=20 {
+        assert_eq!(chain.is_canonical(number, &hash_of(number, 0)), Some(true));
+    }
+    assert_eq!(chain.is_canonical(9, &hash_of(9, 0)), None);
+    assert_eq!(chain.head().unwrap().number, 20);
+}
+
+#[test]
+fn chain_switches_to_other_branch() {
+    let mut chain = HeaderChain::new();
+    extend(&mut chain, 0..=5, 0);
+
+    // shorter branch forking off #2, announced as new head
+    assert_eq!(chain.import(header(3, 1, 0)), Import::Reorg { ancestor: 2 });
+    assert_eq!(chain.head().unwrap().hash, hash_of(3, 1));
+    assert_eq!(chain.canonical_hash(3), Some(&hash_of(3, 1)));
+    assert_eq!(chain.canonical_hash(4), None);
+    assert_eq!(chain.canonical_hash(2), Some(&hash_of(2, 0)));
+
+    // back to longer branch, once it grows
+    assert_eq!(chain.import(header(6, 0, 0)), Import::Reorg { ancestor: 2 });
+    for number in 0..=6 {
+        assert_eq!(chain.is_canonical(number, &hash_of(number, 0)), Some(true));
+    }
+    assert_eq!(chain.is_canonical(3, &hash_of(3, 1)), Some(false));
+}
+
+#[test]
+fn branch_forking_below_pruned_headers_is_not_linked() {
+    let mut chain = HeaderChain::new();
+    extend(&mut chain, 0..=100, 0);
+
+    // long branch forking off #100, then main branch takes over again
+    assert_eq!(chain.import(header(101, 1, 0)), Import::Reorg { ancestor: 100 });
+    extend(&mut chain, 102..=1000, 1);
+    assert_eq!(chain.import(header(101, 0, 0)), Import::Reorg { ancestor: 100 });
+    extend(&mut chain, 102..=MAX_HEADERS + 500, 0);
+
+    // fork point & lower part of other branch are pruned by now, so it
+    // can't be told where both branches meet
+    assert!(chain.canonical_hash(100).is_none());
+    assert_eq!(chain.import(header(1001, 1, 1)), Import::UnknownParent);
+
+    let head = MAX_HEADERS + 500;
+    assert_eq!(chain.head().unwrap().hash, hash_of(head, 0));
+    assert_eq!(chain.canonical_hash(1000), Some(&hash_of(1000, 0)));
+    assert_eq!(chain.canonical_hash(head), Some(&hash_of(head, 0)));
+}
+
+#[test]
+fn ancestors_are_found_on_own_branch_only() {
+    let mut chain = HeaderChain::new();
+    extend(&mut chain, 0..=5, 0);
+    assert_eq!(chain.import(header(3, 1, 0)), Import::Reorg { ancestor: 2 });
+    assert_eq!(chain.import(header(4, 1, 1)), Import::Extended);
+
+    // finalizing #5 of main branch doesn't finalize other branch's #3
+    assert_eq!(chain.ancestor_at(&hash_of(5, 0), 3), Some(hash_of(3, 0)));
+    assert_eq!(chain.ancestor_at(&hash_of(4, 1), 3), Some(hash_of(3, 1)));
+    assert_eq!(chain.ancestor_at(&hash_of(4, 1), 1), Some(hash_of(1, 0)));
+    assert_eq!(chain.ancestor_at(&hash_of(5, 0), 5), Some(hash_of(5, 0)));
+    assert_eq!(chain.ancestor_at(&hash_of(5, 0), 0), Some(hash_of(0, 0)));
+
+    // height above block & unknown block
+    assert_eq!(chain.ancestor_at(&hash_of(3, 0), 4), None);
+    assert_eq!(chain.ancestor_at(&hash_of(9, 0), 3), None);
+}
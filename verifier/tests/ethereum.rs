@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::PathBuf;
+
+use dusk_plonk::bls12_381::{G1Affine, G1Projective};
+
+use verifier::ethereum;
+use verifier::testing::{load_public_params, public_params, Fixture, SrsFormat};
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+fn hex_of(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+// Same public parameters test fixtures are built with, as Ethereum JSON SRS
+fn setup(lagrange: &[G1Affine]) -> serde_json::Value {
+    let pp = public_params();
+
+    serde_json::json!({
+        "g1_monomial": pp.commit_key.powers_of_g.iter().map(|p| hex_of(&p.to_compressed())).collect::<Vec<_>>(),
+        "g1_lagrange": lagrange.iter().map(|p| hex_of(&p.to_compressed())).collect::<Vec<_>>(),
+        "g2_monomial": vec![
+            hex_of(&pp.opening_key.h.to_compressed()),
+            hex_of(&pp.opening_key.beta_h.to_compressed()),
+        ],
+    })
+}
+
+fn write(name: &str, setup: &serde_json::Value) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("verifier-{}-{}.json", name, std::process::id()));
+    fs::write(&path, setup.to_string()).unwrap();
+    path
+}
+
+#[test]
+fn ethereum_srs_matches_dusk_one() {
+    let path = write("srs", &setup(&[]));
+    let loaded = load_public_params(Some(&path), SrsFormat::Ethereum).unwrap();
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(loaded.to_bytes(), public_params().to_bytes());
+}
+
+#[test]
+fn inconsistent_ethereum_srs_is_rejected() {
+    let powers = &public_params().commit_key.powers_of_g;
+
+    // only length of Lagrange points & their sum are checked, which
+    // must be G1 generator
+    let mut lagrange = vec![G1Affine::from(G1Projective::identity()); powers.len()];
+    lagrange[0] = powers[0];
+    let path = write("lagrange", &setup(&lagrange));
+    assert!(load_public_params(Some(&path), SrsFormat::Ethereum).is_ok());
+
+    lagrange[0] = powers[1];
+    fs::write(&path, setup(&lagrange).to_string()).unwrap();
+    assert!(load_public_params(Some(&path), SrsFormat::Ethereum).is_err());
+
+    fs::write(&path, setup(&lagrange[1..]).to_string()).unwrap();
+    assert!(load_public_params(Some(&path), SrsFormat::Ethereum).is_err());
+
+    let mut bad = setup(&[]);
+    bad["g2_monomial"][1] = serde_json::json!("0x1234");
+    fs::write(&path, bad.to_string()).unwrap();
+    assert!(load_public_params(Some(&path), SrsFormat::Ethereum).is_err());
+
+    fs::write(&path, "not json").unwrap();
+    assert!(load_public_params(Some(&path), SrsFormat::Ethereum).is_err());
+    fs::remove_file(path).unwrap();
+
+    // no embedded Ethereum SRS
+    assert!(load_public_params(None, SrsFormat::Ethereum).is_err());
+}
+
+#[test]
+fn bit_reversal_is_an_involution() {
+    assert_eq!(ethereum::reverse_bits(1, 8), 4);
+    assert_eq!(ethereum::reverse_bits(6, 8), 3);
+    assert_eq!(ethereum::reverse_bits(0, 1), 0);
+
+    for size in [2, 16, 256].iter() {
+        for i in 0..*size {
+            assert_eq!(ethereum::reverse_bits(ethereum::reverse_bits(i, *size), *size), i);
+        }
+    }
+}
+
+// Opening of `index`-th element of row, as Ethereum encodes it
+fn ethereum_opening(fixture: &Fixture, row: usize, index: usize) -> Vec<u8> {
+    let mut opening = fixture.proof(row, ethereum::reverse_bits(index, COLS));
+    opening[48..].reverse();
+    opening
+}
+
+#[test]
+fn ethereum_encoded_openings_verify() {
+    let fixture = Fixture::random(ROWS, COLS, 21);
+
+    for row in 0..ROWS * 2 {
+        for index in 0..COLS {
+            let commitment = fixture.row_commitment(row);
+            let opening = ethereum_opening(&fixture, row, index);
+            assert_eq!(ethereum::verify_opening(commitment, &opening, index, COLS), Ok(true));
+
+            // dusk encoded opening isn't accepted as Ethereum one, unless
+            // both happen to coincide
+            let native = fixture.proof(row, index);
+            if native != opening {
+                assert_ne!(ethereum::verify_opening(commitment, &native, index, COLS), Ok(true));
+            }
+        }
+    }
+
+    let opening = ethereum_opening(&fixture, 0, 1);
+    assert!(ethereum::verify_opening(fixture.row_commitment(0), &opening, COLS, COLS).is_err());
+    assert!(ethereum::verify_opening(fixture.row_commitment(0), &opening[..79], 1, COLS).is_err());
+}
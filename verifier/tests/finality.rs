@@ -0,0 +1,137 @@
+use codec::{Compact, Encode};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+use verifier::chain::{DigestItem, ExtrinsicsRoot, Hash, Header, HeaderChain};
+use verifier::finality::{AuthorityList, Error, Finality};
+
+const SET_ID: u64 = 3;
+const ROUND: u64 = 11;
+
+fn keypair(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn authorities(keys: &[Keypair]) -> AuthorityList {
+    keys.iter().map(|k| (k.public.to_bytes(), 1)).collect()
+}
+
+fn hash_of(number: u32) -> Hash {
+    let mut hash = [7u8; 32];
+    hash[..4].copy_from_slice(&number.to_le_bytes());
+    hash
+}
+
+// Precommit for block at `target`, signed by given key, as part of round
+// of given authority set
+fn precommit(key: &Keypair, target: u32, set_id: u64) -> Vec<u8> {
+    let mut payload = vec![1u8];
+    payload.extend((hash_of(target), target).encode());
+    payload.extend((ROUND, set_id).encode());
+
+    let mut signed = (hash_of(target), target).encode();
+    signed.extend_from_slice(&key.sign(&payload).to_bytes());
+    signed.extend_from_slice(&key.public.to_bytes());
+    signed
+}
+
+// SCALE encoded justification of block at `target`, without any ancestry
+fn justification(target: u32, precommits: &[Vec<u8>]) -> Vec<u8> {
+    let mut encoded = ROUND.encode();
+    encoded.extend((hash_of(target), target).encode());
+    Compact(precommits.len() as u32).encode_to(&mut encoded);
+    for p in precommits {
+        encoded.extend_from_slice(p);
+    }
+    Compact(0u32).encode_to(&mut encoded);
+    encoded
+}
+
+fn setup() -> (Vec<Keypair>, Finality) {
+    let keys: Vec<Keypair> = (1..=4).map(keypair).collect();
+    let mut finality = Finality::new();
+    finality.init(SET_ID, authorities(&keys)).unwrap();
+    (keys, finality)
+}
+
+#[test]
+fn supermajority_of_precommits_finalizes_target() {
+    let (keys, mut finality) = setup();
+    let precommits: Vec<Vec<u8>> = keys[..3].iter().map(|k| precommit(k, 10, SET_ID)).collect();
+
+    let target = finality.import_justification(&justification(10, &precommits), &HeaderChain::new());
+    assert_eq!(target, Ok((10, hash_of(10))));
+    assert_eq!(finality.finalized(), Some((10, hash_of(10))));
+}
+
+#[test]
+fn precommits_below_threshold_are_rejected() {
+    let (keys, mut finality) = setup();
+    // 3 out of 4 are needed
+    let precommits: Vec<Vec<u8>> = keys[..2].iter().map(|k| precommit(k, 10, SET_ID)).collect();
+
+    let target = finality.import_justification(&justification(10, &precommits), &HeaderChain::new());
+    assert_eq!(target, Err(Error::InsufficientWeight));
+    assert_eq!(finality.finalized(), None);
+}
+
+#[test]
+fn precommits_of_other_set_are_rejected() {
+    let (keys, mut finality) = setup();
+    let precommits: Vec<Vec<u8>> = keys[..3].iter().map(|k| precommit(k, 10, SET_ID + 1)).collect();
+
+    let target = finality.import_justification(&justification(10, &precommits), &HeaderChain::new());
+    assert_eq!(target, Err(Error::BadSignature));
+}
+
+#[test]
+fn precommits_for_other_target_are_rejected() {
+    let (keys, mut finality) = setup();
+    let mut precommits: Vec<Vec<u8>> = keys[..2].iter().map(|k| precommit(k, 10, SET_ID)).collect();
+    // not descendant of commit target, as no ancestry is supplied
+    precommits.push(precommit(&keys[2], 12, SET_ID));
+
+    let target = finality.import_justification(&justification(10, &precommits), &HeaderChain::new());
+    assert_eq!(target, Err(Error::NotDescendant));
+}
+
+#[test]
+fn empty_or_weightless_authority_sets_are_rejected() {
+    let mut finality = Finality::new();
+    assert_eq!(finality.init(SET_ID, vec![]), Err(Error::InvalidAuthoritySet));
+    assert_eq!(finality.init(SET_ID, vec![([1u8; 32], 0)]), Err(Error::InvalidAuthoritySet));
+
+    let precommits = vec![precommit(&keypair(1), 10, SET_ID)];
+    let target = finality.import_justification(&justification(10, &precommits), &HeaderChain::new());
+    assert_eq!(target, Err(Error::NoAuthoritySet));
+}
+
+#[test]
+fn scheduled_change_to_empty_set_is_ignored() {
+    let (keys, mut finality) = setup();
+
+    let mut change = vec![1u8];
+    change.extend((AuthorityList::new(), 0u32).encode());
+    finality.note_header(&Header {
+        hash: hash_of(10),
+        parent_hash: hash_of(9),
+        number: 10,
+        state_root: [0u8; 32],
+        extrinsics_root: ExtrinsicsRoot {
+            hash: [0u8; 32],
+            commitment: vec![],
+            rows: 0,
+            cols: 0,
+        },
+        logs: vec![DigestItem::Consensus(*b"FRNK", change)],
+        app_data_lookup: Default::default(),
+    });
+
+    // change would be due, once #10 is finalized
+    for target in [10, 11].iter() {
+        let precommits: Vec<Vec<u8>> = keys[..3].iter().map(|k| precommit(k, *target, SET_ID)).collect();
+        let finalized = finality.import_justification(&justification(*target, &precommits), &HeaderChain::new());
+        assert_eq!(finalized, Ok((*target as u64, hash_of(*target))));
+    }
+}
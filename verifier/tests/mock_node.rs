@@ -0,0 +1,189 @@
+use std::net::TcpStream;
+
+use serde_json::{json, Value};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{connect, Message, WebSocket};
+
+use verifier::mock_node::{Misbehaviour, MockConfig, MockNode};
+
+type Socket = WebSocket<MaybeTlsStream<TcpStream>>;
+
+fn start(misbehaviour: Misbehaviour) -> (MockNode, Socket) {
+    let node = MockNode::start(
+        "127.0.0.1:0".parse().unwrap(),
+        MockConfig {
+            misbehaviour,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let (socket, _) = connect(node.uri()).unwrap();
+
+    (node, socket)
+}
+
+// Next message, which is response to request with given id or any
+// notification, if id is not given
+fn next(socket: &mut Socket, id: Option<u64>) -> Value {
+    loop {
+        if let Message::Text(text) = socket.read_message().unwrap() {
+            let msg: Value = serde_json::from_str(&text).unwrap();
+            match id {
+                Some(id) if msg["id"] == json!(id) => return msg,
+                None if msg["method"].is_string() => return msg,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn rpc(socket: &mut Socket, id: u64, method: &str, params: Value) -> Value {
+    let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+    socket.write_message(Message::Text(request.to_string())).unwrap();
+
+    next(socket, Some(id))
+}
+
+fn bytes_of(v: &Value) -> Vec<u8> {
+    hex::decode(v.as_str().unwrap().trim_start_matches("0x")).unwrap()
+}
+
+// Fetches proofs of given cells of block & verifies them, against
+// commitment carried in its header
+fn query_and_verify(socket: &mut Socket, number: u64, cells: &[(u8, u8)]) -> Result<u8, Value> {
+    let hash = rpc(socket, 10, "chain_getBlockHash", json!([number]))["result"].clone();
+    let header = rpc(socket, 11, "chain_getHeader", json!([hash]))["result"].clone();
+    let commitment = bytes_of(&header["extrinsicsRoot"]["commitment"]);
+
+    let query: Vec<Value> = cells.iter().map(|(row, col)| json!({ "row": row, "col": col })).collect();
+    let resp = rpc(socket, 12, "kate_queryProof", json!([number, query]));
+    if !resp["error"].is_null() {
+        return Err(resp["error"].clone());
+    }
+
+    let proof: Vec<u8> = serde_json::from_value(resp["result"].clone()).unwrap();
+    let rows: Vec<u8> = cells.iter().map(|(row, _)| *row).collect();
+    let cols: Vec<u8> = cells.iter().map(|(_, col)| *col).collect();
+
+    Ok(verifier::verify_proof(
+        number,
+        header["extrinsicsRoot"]["rows"].as_u64().unwrap() as usize,
+        header["extrinsicsRoot"]["cols"].as_u64().unwrap() as usize,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    ))
+}
+
+const CELLS: [(u8, u8); 4] = [(0, 0), (1, 7), (5, 3), (7, 7)];
+
+#[test]
+fn new_heads_are_pushed_to_subscribers() {
+    let (node, mut socket) = start(Misbehaviour::Honest);
+
+    let id = rpc(&mut socket, 1, "chain_subscribeNewHeads", json!([]))["result"].clone();
+    let hash = node.produce_block();
+
+    let notification = next(&mut socket, None);
+    assert_eq!(notification["params"]["subscription"], id);
+    assert_eq!(notification["params"]["result"]["number"], json!("0x1"));
+
+    let expected = format!("0x{}", hex::encode(hash));
+    assert_eq!(rpc(&mut socket, 2, "chain_getBlockHash", json!([1]))["result"], json!(expected));
+    assert_eq!(rpc(&mut socket, 3, "chain_getBlockHash", json!(["0x1"]))["result"], json!(expected));
+    assert_eq!(rpc(&mut socket, 4, "chain_getBlockHash", json!([2]))["result"], Value::Null);
+
+    let block = rpc(&mut socket, 5, "chain_getBlock", json!([expected]))["result"].clone();
+    assert_eq!(block["block"]["header"], notification["params"]["result"]);
+
+    // header hash is blake2b-256 of SCALE encoded header
+    let encoded = node.encoded_header(1).unwrap();
+    assert_eq!(blake2_rfc::blake2b::blake2b(32, &[], &encoded).as_bytes(), &hash[..]);
+    let parent = rpc(&mut socket, 6, "chain_getBlockHash", json!([0]))["result"].clone();
+    assert_eq!(notification["params"]["result"]["parentHash"], parent);
+}
+
+#[test]
+fn honest_node_serves_valid_proofs() {
+    let (node, mut socket) = start(Misbehaviour::Honest);
+    node.produce_block();
+
+    assert_eq!(query_and_verify(&mut socket, 1, &CELLS), Ok(CELLS.len() as u8));
+
+    // header advertises rows of original matrix, each extended to two
+    let header = rpc(&mut socket, 6, "chain_getHeader", json!([]))["result"].clone();
+    let committed_rows = bytes_of(&header["extrinsicsRoot"]["commitment"]).len() / 48;
+    assert_eq!(header["extrinsicsRoot"]["rows"].as_u64().unwrap() as usize * 2, committed_rows);
+
+    // proofs match fixture, block was built from
+    let expected = node.with_block(1, |_, fixture| fixture.cell_data(5, 3)).unwrap();
+    let resp = rpc(&mut socket, 7, "kate_queryProof", json!([1, [{ "row": 5, "col": 3 }]]));
+    let proof: Vec<u8> = serde_json::from_value(resp["result"].clone()).unwrap();
+    assert_eq!(&proof[48..], &expected[..]);
+}
+
+#[test]
+fn withholding_node_refuses_proofs() {
+    let (node, mut socket) = start(Misbehaviour::WithholdCells(1.0));
+    node.produce_block();
+
+    assert!(query_and_verify(&mut socket, 1, &CELLS).is_err());
+}
+
+#[test]
+fn bad_proofs_fail_verification() {
+    let (node, mut socket) = start(Misbehaviour::BadProofs(1.0));
+    node.produce_block();
+
+    assert_eq!(query_and_verify(&mut socket, 1, &CELLS), Ok(0));
+}
+
+#[test]
+fn wrong_dimensions_are_advertised() {
+    let (node, mut socket) = start(Misbehaviour::WrongDimensions);
+    node.produce_block();
+
+    let header = rpc(&mut socket, 1, "chain_getHeader", json!([]))["result"].clone();
+    // honest header advertises half as many rows as committed ones
+    let committed_rows = bytes_of(&header["extrinsicsRoot"]["commitment"]).len() / 48;
+    assert_ne!(header["extrinsicsRoot"]["rows"].as_u64().unwrap() as usize * 2, committed_rows);
+
+    // cells beyond committed rows aren't served
+    assert!(query_and_verify(&mut socket, 1, &[(committed_rows as u8, 0)]).is_err());
+}
+
+#[test]
+fn multiproofs_of_row_ranges_are_served() {
+    let (node, mut socket) = start(Misbehaviour::Honest);
+    node.produce_block();
+
+    let hash = rpc(&mut socket, 1, "chain_getBlockHash", json!([1]))["result"].clone();
+    let header = rpc(&mut socket, 2, "chain_getHeader", json!([hash]))["result"].clone();
+    let commitment = bytes_of(&header["extrinsicsRoot"]["commitment"]);
+
+    let cols = [2u16, 3, 4, 5];
+    let resp = rpc(&mut socket, 3, "kate_queryMultiProof", json!([1, 5, cols]));
+    let proof: Vec<u8> = serde_json::from_value(resp["result"].clone()).unwrap();
+
+    let status = verifier::verify_multiproof(
+        1,
+        header["extrinsicsRoot"]["cols"].as_u64().unwrap() as usize,
+        5,
+        cols.as_ptr(),
+        cols.len(),
+        commitment.as_ptr(),
+        commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    );
+    assert_eq!(status, 1);
+
+    // row beyond matrix
+    let resp = rpc(&mut socket, 4, "kate_queryMultiProof", json!([1, 1000, cols]));
+    assert!(!resp["error"].is_null());
+}
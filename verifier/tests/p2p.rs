@@ -0,0 +1,104 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use verifier::p2p::{cell_key, Alert, Network};
+
+// Peer discovery & gossip delivery are waited for at max these long
+const DEADLINE: Duration = Duration::from_secs(30);
+
+// Starts peer listening on random local port
+fn peer(bootstrap: &[libp2p::Multiaddr]) -> Network {
+    Network::start("/ip4/127.0.0.1/tcp/0".parse().unwrap(), bootstrap).expect("peer to start")
+}
+
+// Polls `attempt` till it succeeds or deadline passes, so that tests don't
+// depend on how long routing tables & subscriptions take to settle
+fn eventually<T>(mut attempt: impl FnMut() -> Option<T>) -> Option<T> {
+    let deadline = Instant::now() + DEADLINE;
+    loop {
+        if let Some(v) = attempt() {
+            return Some(v);
+        }
+        if Instant::now() >= deadline {
+            return None;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn cell_published_by_one_peer_is_found_by_another() {
+    let a = peer(&[]);
+    let b = peer(&[a.address()]);
+    let c = peer(&[a.address()]);
+
+    let hash = [7u8; 32];
+    let proof = vec![42u8; 80];
+    a.put(cell_key(&hash, 1, 2), proof.clone());
+
+    // record is put again, till routing tables settle & it reaches peer
+    for p in [&b, &c].iter() {
+        let found = eventually(|| {
+            let found = p.get_many(vec![cell_key(&hash, 1, 2), cell_key(&hash, 2, 1)], Duration::from_secs(1));
+            if found[0].is_none() {
+                a.put(cell_key(&hash, 1, 2), proof.clone());
+                return None;
+            }
+            Some(found)
+        });
+        assert_eq!(found, Some(vec![Some(proof.clone()), None]));
+    }
+}
+
+#[test]
+fn bootstrap_address_must_carry_peer_id() {
+    let addr = "/ip4/127.0.0.1/tcp/1".parse().unwrap();
+    assert!(Network::start("/ip4/127.0.0.1/tcp/0".parse().unwrap(), &[addr]).is_err());
+}
+
+#[test]
+fn alert_is_gossiped_to_other_peers() {
+    let a = peer(&[]);
+    let b = peer(&[a.address()]);
+    let c = peer(&[a.address()]);
+
+    let alert = Alert {
+        hash: [9u8; 32],
+        number: 10,
+        commitment: vec![1u8; 48 * 2],
+        cells: vec![(0, 3), (1, 5)],
+    };
+
+    // alerts are deduplicated by content, so each retry, made till peers
+    // exchange subscriptions, is about next block number
+    let mut number = alert.number;
+    for p in [&a, &c].iter() {
+        let received = eventually(|| {
+            let received = p.next_alert();
+            if received.is_none() {
+                b.announce(Alert { number, ..alert.clone() });
+                number += 1;
+            }
+            received
+        });
+        assert_eq!(received.map(|r| Alert { number: alert.number, ..r }), Some(alert.clone()));
+    }
+
+    // sender doesn't hear its own alert
+    assert_eq!(b.next_alert(), None);
+}
+
+#[test]
+fn malformed_alert_is_rejected() {
+    let alert = Alert {
+        hash: [0u8; 32],
+        number: 1,
+        commitment: vec![1u8; 48],
+        cells: vec![(1, 0)],
+    };
+    assert!(alert.validate().is_err());
+
+    assert!(Alert { cells: vec![], ..alert.clone() }.validate().is_err());
+    assert!(Alert { commitment: vec![1u8; 47], ..alert.clone() }.validate().is_err());
+    assert!(Alert { cells: vec![(0, 0)], ..alert }.validate().is_ok());
+}
@@ -0,0 +1,174 @@
+use std::ffi::{CStr, CString};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use verifier::report::{self, BlockVerificationReport, Outcome, SampledCell};
+use verifier::scheme::Scheme;
+use verifier::testing::Fixture;
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+fn storage() -> PathBuf {
+    std::env::temp_dir().join(format!("verifier-reports-{}", std::process::id()))
+}
+
+fn cell(row: u16, col: u16, outcome: Outcome) -> SampledCell {
+    SampledCell {
+        row,
+        col,
+        outcome,
+        micros: 10,
+    }
+}
+
+fn report_of(cells: Vec<SampledCell>) -> BlockVerificationReport {
+    BlockVerificationReport::new(7, [7u8; 32], ROWS, COLS, Scheme::Row, cells, Duration::from_millis(2))
+}
+
+// Takes over string handed over through C ABI
+fn take(ptr: *mut std::os::raw::c_char) -> serde_json::Value {
+    let resp = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+    verifier::free_string(ptr);
+    serde_json::from_str(&resp).unwrap()
+}
+
+#[test]
+fn confidence_accounts_for_sample_set() {
+    let total = ROWS * 2 * COLS;
+    // block is unrecoverable once `ROWS + 1` cells of one column are withheld
+    let withheld = (ROWS + 1) as f64 / total as f64;
+
+    assert_eq!(report::confidence(ROWS, COLS, 0), 0.0);
+    assert!((report::confidence(ROWS, COLS, 1) - withheld * 100.0).abs() < 1e-9);
+    // distinct cells out of small matrix give more than sampling with replacement
+    assert!(report::confidence(ROWS, COLS, 10) > (1.0 - (1.0 - withheld).powi(10)) * 100.0);
+    // more than half of cells verified still leaves enough to withhold
+    assert!(report::confidence(ROWS, COLS, total / 2 + 1) < 100.0);
+    // at most `ROWS` cells unverified, every column is recoverable
+    assert!(report::confidence(ROWS, COLS, total - ROWS - 1) < 100.0);
+    assert_eq!(report::confidence(ROWS, COLS, total - ROWS), 100.0);
+    assert_eq!(report::confidence(ROWS, COLS, total), 100.0);
+}
+
+#[test]
+fn reports_of_same_block_are_merged() {
+    let mut report = report_of(vec![cell(0, 1, Outcome::Verified), cell(2, 3, Outcome::Failed)]);
+    assert_eq!(report.verified(), 1);
+    assert_eq!(report.confidence, report::confidence(ROWS, COLS, 1));
+
+    // failed cell verifies on resampling, verified one stays verified
+    report.merge(report_of(vec![
+        cell(2, 3, Outcome::Verified),
+        cell(0, 1, Outcome::Malformed),
+        cell(5, 5, Outcome::Verified),
+    ]));
+
+    assert_eq!(report.cells.len(), 3);
+    assert_eq!(report.verified(), 3);
+    assert_eq!(report.micros, 4000);
+    assert_eq!(report.confidence, report::confidence(ROWS, COLS, 3));
+}
+
+#[test]
+fn report_round_trips_through_json_cbor_and_storage() {
+    // single verified cell, so that confidence i.e. 5 / 64 survives JSON exactly
+    let report = report_of(vec![cell(0, 1, Outcome::Verified), cell(2, 3, Outcome::Malformed)]);
+
+    let json = report.to_json();
+    assert_eq!(json["hash"], format!("0x{}", "07".repeat(32)));
+    assert_eq!(json["totalRows"], ROWS);
+    assert_eq!(json["scheme"], "row");
+    assert_eq!(json["cells"][1]["outcome"], "malformed");
+    assert!(json["srs"].as_str().unwrap().starts_with("dusk:0x"));
+    assert_eq!(serde_json::from_value::<BlockVerificationReport>(json).unwrap(), report);
+
+    assert_eq!(BlockVerificationReport::from_cbor(&report.to_cbor()).unwrap(), report);
+    assert!(BlockVerificationReport::from_cbor(&[0xff, 0x00]).is_err());
+
+    let dir = storage().join("round-trip");
+    assert_eq!(report::load(&dir, &report.hash).unwrap(), None);
+    report::persist(&dir, &report).unwrap();
+    assert_eq!(report::load(&dir, &report.hash).unwrap(), Some(report));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn verified_cells_are_reported_across_c_abi() {
+    let dir = storage().join("ffi");
+    let args = CString::new(serde_json::json!(["--storage-path", dir.to_str().unwrap()]).to_string()).unwrap();
+    let resp = take(verifier::load_config(args.as_ptr()));
+    assert!(resp["config"].is_object(), "{}", resp);
+    assert!(!dir.exists());
+    let resp = take(verifier::init_verifier());
+    assert!(resp["error"].is_null(), "{}", resp);
+    assert!(dir.is_dir());
+
+    let fixture = Fixture::random(ROWS, COLS, 31);
+    let hash = [3u8; 32];
+
+    // two rounds, second cell of first round fails to verify
+    let rounds = [[(0usize, 0usize), (5, 7)], [(2, 3), (7, 1)]];
+    for (round, cells) in rounds.iter().enumerate() {
+        let rows: Vec<u16> = cells.iter().map(|&(row, _)| row as u16).collect();
+        let cols: Vec<u16> = cells.iter().map(|&(_, col)| col as u16).collect();
+        let mut proof = fixture.proofs(cells);
+        if round == 0 {
+            proof[80 + 48..160].copy_from_slice(&fixture.cell_data(0, 0));
+        }
+        let mut out = vec![0u8; cells.len() * 33];
+        let mut confidence = -1f64;
+
+        verifier::verify_proof_with_data(
+            9,
+            hash.as_ptr(),
+            hash.len(),
+            ROWS,
+            COLS,
+            rows.as_ptr(),
+            rows.len(),
+            cols.as_ptr(),
+            cols.len(),
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            proof.as_ptr(),
+            proof.len(),
+            out.as_mut_ptr(),
+            out.len(),
+            &mut confidence,
+        );
+
+        // confidence of report, merged across rounds
+        let verified = [1, 3][round];
+        assert_eq!(confidence, report::confidence(ROWS, COLS, verified));
+    }
+
+    let resp = take(verifier::block_report(hash.as_ptr(), hash.len()));
+    let report: BlockVerificationReport = serde_json::from_value(resp["report"].clone()).unwrap();
+    assert_eq!(report.number, 9);
+    assert_eq!(report.cells.len(), 4);
+    assert_eq!(report.verified(), 3);
+    assert_eq!(report.cells[1].outcome, Outcome::Failed);
+    // floats needn't survive JSON exactly
+    assert!((report.confidence - report::confidence(ROWS, COLS, 3)).abs() < 1e-9);
+
+    // CBOR, after asking for length
+    let len = verifier::block_report_cbor(hash.as_ptr(), hash.len(), std::ptr::null_mut(), 0);
+    assert!(len > 0);
+    let mut cbor = vec![0u8; len as usize];
+    assert_eq!(verifier::block_report_cbor(hash.as_ptr(), hash.len(), cbor.as_mut_ptr(), cbor.len()), len);
+    let decoded = BlockVerificationReport::from_cbor(&cbor).unwrap();
+    assert_eq!((decoded.hash, &decoded.cells, &decoded.srs), (report.hash, &report.cells, &report.srs));
+
+    // unknown block, even if its stored report is corrupt, & malformed hash
+    let other = [4u8; 32];
+    std::fs::write(dir.join("reports").join(format!("0x{}.cbor", "04".repeat(32))), [0xff, 0x00]).unwrap();
+    assert!(take(verifier::block_report(other.as_ptr(), other.len()))["report"].is_null());
+    assert_eq!(verifier::block_report_cbor(other.as_ptr(), other.len(), std::ptr::null_mut(), 0), 0);
+    assert_eq!(verifier::block_report_cbor(hash.as_ptr(), 31, std::ptr::null_mut(), 0), -1);
+
+    // report is kept in memory, regardless of storage
+    std::fs::remove_dir_all(dir).unwrap();
+    let resp = take(verifier::block_report(hash.as_ptr(), hash.len()));
+    assert_eq!(resp["report"]["cells"].as_array().unwrap().len(), 4);
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use verifier::sampling::{sample_cells, Rng};
+use verifier::simulation::{computed_confidence, expected_detection_rate, run, SimulationConfig};
+
+fn simulate(withheld_fraction: f64, sample_count: usize) -> f64 {
+    run(&SimulationConfig {
+        rows: 4,
+        cols: 8,
+        withheld_fraction,
+        sample_count,
+        trials: 200,
+        blocks: 1,
+        seed: 1,
+    })
+    .unwrap()
+    .detection_rate
+}
+
+#[test]
+fn nothing_withheld_is_never_detected() {
+    assert_eq!(simulate(0.0, 10), 0.0);
+}
+
+#[test]
+fn everything_withheld_is_always_detected() {
+    assert_eq!(simulate(1.0, 1), 1.0);
+}
+
+#[test]
+fn half_withheld_is_detected_as_often_as_expected() {
+    let rate = simulate(0.5, 3);
+    let expected = expected_detection_rate(64, 32, 3);
+
+    assert!((rate - expected).abs() < 0.1, "observed {}, expected {}", rate, expected);
+}
+
+#[test]
+fn confidence_matches_detection_of_least_withholding() {
+    // withholding one more cell of a column, than its original rows, is
+    // least that makes block unrecoverable
+    let expected = expected_detection_rate(64, 5, 3);
+    assert!((expected * 100.0 - computed_confidence(4, 8, 3)).abs() < 1e-9);
+
+    // withholding half of cells is detected far more often than confidence
+    // tells, as it's only lower bound
+    assert!(expected_detection_rate(64, 32, 3) * 100.0 > computed_confidence(4, 8, 3));
+}
+
+// Cells of block, picked round after round, as light client does
+fn sample_rounds(rows: usize, cols: usize, per_round: usize, seed: u64) -> Vec<Vec<(u16, u16)>> {
+    let mut rng = Rng::new(seed);
+    let mut sampled = HashSet::new();
+    let mut rounds = Vec::new();
+
+    loop {
+        let cells = sample_cells(rows, cols, &sampled, per_round, &mut rng);
+        if cells.is_empty() {
+            return rounds;
+        }
+
+        sampled.extend(cells.iter().cloned());
+        rounds.push(cells);
+    }
+}
+
+#[test]
+fn sampler_covers_whole_extended_matrix() {
+    let rounds = sample_rounds(4, 8, 5, 2);
+    let cells: Vec<(u16, u16)> = rounds.iter().flatten().cloned().collect();
+
+    // 64 cells, in rounds of 5, last one picks what's left
+    assert_eq!(rounds.len(), 13);
+    assert_eq!(rounds.last().unwrap().len(), 4);
+    assert_eq!(cells.iter().collect::<HashSet<_>>().len(), 64);
+    assert!(cells.iter().all(|&(row, col)| row < 8 && col < 8));
+}
+
+#[test]
+fn withholding_extended_rows_is_detected_as_confidence_tells() {
+    let (rows, cols, samples) = (4, 8, 3);
+    // whole bottom half, which earlier sampler never picked from, & one cell
+    // of each column in top half, leaving `rows - 1` cells of each column
+    let withheld = |(row, _): (u16, u16)| row == 0 || row as usize >= rows;
+
+    let trials = 2000;
+    let detected = (0..trials)
+        .filter(|&seed| {
+            sample_cells(rows, cols, &HashSet::new(), samples, &mut Rng::new(seed))
+                .into_iter()
+                .any(withheld)
+        })
+        .count();
+
+    let rate = detected as f64 / trials as f64;
+    let expected = expected_detection_rate(64, (rows + 1) * cols, samples);
+    assert!((rate - expected).abs() < 0.05, "observed {}, expected {}", rate, expected);
+    assert!(rate * 100.0 > computed_confidence(rows, cols, samples));
+}
@@ -0,0 +1,417 @@
+use codec::{Compact, Encode};
+use dusk_plonk::prelude::BlsScalar;
+
+use verifier::testing::{public_params, random_scalars, Fixture};
+
+const ROWS: usize = 4;
+const COLS: usize = 8;
+
+// Verifies given cells through C ABI, same way light client does
+fn verify(fixture: &Fixture, cells: &[(usize, usize)], proof: &[u8]) -> u8 {
+    let rows: Vec<u8> = cells.iter().map(|&(row, _)| row as u8).collect();
+    let cols: Vec<u8> = cells.iter().map(|&(_, col)| col as u8).collect();
+
+    verifier::verify_proof(
+        1,
+        fixture.total_rows,
+        fixture.total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        fixture.commitment.as_ptr(),
+        fixture.commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    )
+}
+
+#[test]
+fn fixture_is_deterministic() {
+    let a = Fixture::random(ROWS, COLS, 1);
+    let b = Fixture::random(ROWS, COLS, 1);
+    let c = Fixture::random(ROWS, COLS, 2);
+
+    assert_eq!(a.commitment, b.commitment);
+    assert_eq!(a.proof(3, 5), b.proof(3, 5));
+    assert_ne!(a.commitment, c.commitment);
+}
+
+#[test]
+fn original_data_lands_on_even_rows() {
+    let data = random_scalars(7, ROWS * COLS);
+    let fixture = Fixture::new(public_params(), ROWS, COLS, &data).unwrap();
+
+    for row in 0..ROWS {
+        assert_eq!(&fixture.matrix[row * 2][..], &data[row * COLS..(row + 1) * COLS]);
+    }
+}
+
+#[test]
+fn proofs_of_all_cells_verify() {
+    let fixture = Fixture::random(ROWS, COLS, 3);
+    let cells: Vec<(usize, usize)> = (0..ROWS * 2).flat_map(|row| (0..COLS).map(move |col| (row, col))).collect();
+
+    assert_eq!(verify(&fixture, &cells, &fixture.proofs(&cells)) as usize, cells.len());
+}
+
+#[test]
+fn tampered_proofs_fail() {
+    let fixture = Fixture::random(ROWS, COLS, 4);
+    let cells = [(0, 0), (5, 7), (2, 3)];
+
+    // evaluation of second cell swapped with some other value
+    let mut proof = fixture.proofs(&cells);
+    proof[80 + 48..160].copy_from_slice(&BlsScalar::from(42).to_bytes());
+    assert_eq!(verify(&fixture, &cells, &proof), 2);
+
+    // proof of one cell presented for another
+    let proof = fixture.proofs(&[(0, 0), (5, 6), (2, 3)]);
+    assert_eq!(verify(&fixture, &cells, &proof), 2);
+
+    // commitment of some other block
+    let other = Fixture::random(ROWS, COLS, 5);
+    assert_eq!(verify(&other, &cells, &fixture.proofs(&cells)), 0);
+}
+
+#[test]
+fn whole_row_verifies_against_commitment() {
+    let fixture = Fixture::random(ROWS, COLS, 6);
+
+    for row in 0..ROWS * 2 {
+        let evals: Vec<u8> = (0..COLS).flat_map(|col| fixture.cell_data(row, col).to_vec()).collect();
+        let commitment = fixture.row_commitment(row);
+
+        assert_eq!(
+            verifier::verify_row(commitment.as_ptr(), commitment.len(), evals.as_ptr(), evals.len(), COLS),
+            1
+        );
+
+        let other = fixture.row_commitment((row + 1) % (ROWS * 2));
+        assert_eq!(verifier::verify_row(other.as_ptr(), other.len(), evals.as_ptr(), evals.len(), COLS), 0);
+    }
+}
+
+// Reconstructs matrix from given cells, returning status
+fn reconstruct(fixture: &Fixture, cells: &[(usize, usize)]) -> (i8, Vec<u8>) {
+    let rows: Vec<u16> = cells.iter().map(|&(row, _)| row as u16).collect();
+    let cols: Vec<u16> = cells.iter().map(|&(_, col)| col as u16).collect();
+    let data: Vec<u8> = cells.iter().flat_map(|&(row, col)| fixture.cell_data(row, col).to_vec()).collect();
+    let mut out = vec![0u8; ROWS * 2 * COLS * 32];
+
+    let status = verifier::reconstruct_matrix(
+        ROWS,
+        COLS,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        data.as_ptr(),
+        data.len(),
+        fixture.commitment.as_ptr(),
+        fixture.commitment.len(),
+        out.as_mut_ptr(),
+        out.len(),
+    );
+
+    (status, out)
+}
+
+#[test]
+fn matrix_is_reconstructed_from_half_of_cells() {
+    let fixture = Fixture::random(ROWS, COLS, 8);
+
+    // odd rows i.e. only parity
+    let cells: Vec<(usize, usize)> = (0..ROWS).flat_map(|row| (0..COLS).map(move |col| (row * 2 + 1, col))).collect();
+    let (status, out) = reconstruct(&fixture, &cells);
+    assert_eq!(status, 1);
+
+    let expected: Vec<u8> = fixture.matrix.iter().flatten().flat_map(|e| e.to_bytes().to_vec()).collect();
+    assert_eq!(out, expected);
+
+    // one row short, for every column
+    assert_eq!(reconstruct(&fixture, &cells[COLS..]).0, -2);
+}
+
+// Fixture, where one parity cell of column 2 is replaced, while its row
+// is still committed to, as is
+fn incorrectly_encoded() -> Fixture {
+    let good = Fixture::random(ROWS, COLS, 9);
+
+    let mut matrix = good.matrix.clone();
+    matrix[1][2] += BlsScalar::one();
+
+    Fixture::from_extended(public_params(), ROWS, COLS, matrix).unwrap()
+}
+
+// Builds bad encoding proof for column, from all of its cells
+fn fraud_proof(fixture: &Fixture, col: usize) -> Result<Vec<u8>, i64> {
+    let hash = [1u8; 32];
+    let rows: Vec<u16> = (0..ROWS as u16 * 2).collect();
+    let proof = fixture.proofs(&rows.iter().map(|&row| (row as usize, col)).collect::<Vec<_>>());
+
+    let args = |out: &mut Vec<u8>| {
+        verifier::build_fraud_proof(
+            hash.as_ptr(),
+            hash.len(),
+            ROWS,
+            COLS,
+            col,
+            rows.as_ptr(),
+            rows.len(),
+            proof.as_ptr(),
+            proof.len(),
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+
+    let len = args(&mut vec![0u8; 0]);
+    if len < 0 {
+        return Err(len);
+    }
+
+    let mut out = vec![0u8; len as usize];
+    assert_eq!(args(&mut out), len);
+    Ok(out)
+}
+
+#[test]
+fn bad_encoding_is_proven() {
+    let fixture = incorrectly_encoded();
+    let cells: Vec<(usize, usize)> = (0..ROWS * 2).flat_map(|row| (0..COLS).map(move |col| (row, col))).collect();
+    assert_eq!(reconstruct(&fixture, &cells).0, -3);
+
+    let proof = fraud_proof(&fixture, 2).unwrap();
+    let verify_fraud = |proof: &[u8], commitment: &[u8]| {
+        verifier::verify_fraud_proof(proof.as_ptr(), proof.len(), ROWS, COLS, commitment.as_ptr(), commitment.len())
+    };
+    assert_eq!(verify_fraud(&proof, &fixture.commitment), 1);
+
+    // against commitment of correctly encoded block, openings don't verify
+    assert_eq!(verify_fraud(&proof, &Fixture::random(ROWS, COLS, 9).commitment), -2);
+
+    // trailing garbage
+    let mut longer = proof.clone();
+    longer.push(0);
+    assert_eq!(verify_fraud(&longer, &fixture.commitment), -1);
+
+    // other columns are fine
+    assert_eq!(fraud_proof(&fixture, 3), Err(-4));
+}
+
+#[test]
+fn fraud_proof_with_forged_dimensions_is_rejected() {
+    let fixture = Fixture::random(ROWS, COLS, 11);
+    let col = 2;
+
+    // honest column, claimed to be of half as many rows, so that its first
+    // cells are interpolated over smaller domain, which they don't lie on
+    let total_rows = ROWS / 2;
+    let mut forged = [1u8; 32].encode();
+    (total_rows as u16, COLS as u16, col as u16).encode_to(&mut forged);
+    Compact((total_rows + 1) as u32).encode_to(&mut forged);
+    for row in 0..total_rows + 1 {
+        (row as u16, fixture.proof(row, col)).encode_to(&mut forged);
+    }
+
+    let verify_fraud = |total_rows: usize, commitment: &[u8]| {
+        verifier::verify_fraud_proof(forged.as_ptr(), forged.len(), total_rows, COLS, commitment.as_ptr(), commitment.len())
+    };
+    assert_eq!(verify_fraud(ROWS, &fixture.commitment), -1);
+    // header dimensions, which don't match its commitment
+    assert_eq!(verify_fraud(total_rows, &fixture.commitment), -1);
+}
+
+#[test]
+fn malformed_inputs_are_rejected_without_panicking() {
+    let fixture = Fixture::random(ROWS, COLS, 10);
+    let cells = [(0, 0), (5, 7)];
+    let proof = fixture.proofs(&cells);
+
+    // truncated proof & proof with trailing bytes
+    assert_eq!(verify(&fixture, &cells, &proof[..proof.len() - 1]), 0);
+    let mut longer = proof.clone();
+    longer.push(0);
+    assert_eq!(verify(&fixture, &cells, &longer), 0);
+
+    // witness which isn't a point on curve
+    let mut bad_witness = proof.clone();
+    bad_witness[..48].copy_from_slice(&[0xffu8; 48]);
+    assert_eq!(verify(&fixture, &cells, &bad_witness), 1);
+
+    // evaluation which isn't a canonical scalar
+    let mut bad_eval = proof.clone();
+    bad_eval[48..80].copy_from_slice(&[0xffu8; 32]);
+    assert_eq!(verify(&fixture, &cells, &bad_eval), 1);
+
+    // row without commitment
+    assert_eq!(verify(&fixture, &[(ROWS * 2, 0), (5, 7)], &proof), 0);
+
+    // columns out of matrix, or matrix which can't be described by header
+    let rows = [0u8];
+    let cols = [0u8];
+    let verify_with = |total_rows: usize, total_cols: usize| {
+        verifier::verify_proof(
+            1,
+            total_rows,
+            total_cols,
+            rows.as_ptr(),
+            rows.len(),
+            cols.as_ptr(),
+            cols.len(),
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            proof.as_ptr(),
+            80,
+        )
+    };
+    assert_eq!(verify_with(ROWS, 0), 0);
+    assert_eq!(verify_with(ROWS, usize::MAX), 0);
+    assert_eq!(verify_with(usize::MAX, COLS), 0);
+
+    // output buffer for dimensions, which overflow
+    let mut out = vec![0u8; 32];
+    let (empty, no_data): ([u16; 0], [u8; 0]) = ([], []);
+    assert_eq!(
+        verifier::reconstruct_matrix(
+            usize::MAX,
+            2,
+            empty.as_ptr(),
+            0,
+            empty.as_ptr(),
+            0,
+            no_data.as_ptr(),
+            0,
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        ),
+        -1
+    );
+}
+
+// Verifies given cells against column commitments, through C ABI
+fn verify_columns(fixture: &Fixture, cells: &[(usize, usize)], proof: &[u8]) -> u8 {
+    let rows: Vec<u8> = cells.iter().map(|&(row, _)| row as u8).collect();
+    let cols: Vec<u8> = cells.iter().map(|&(_, col)| col as u8).collect();
+
+    verifier::verify_proof_with_scheme(
+        1,
+        1,
+        fixture.total_rows,
+        fixture.total_cols,
+        rows.as_ptr(),
+        rows.len(),
+        cols.as_ptr(),
+        cols.len(),
+        fixture.column_commitment.as_ptr(),
+        fixture.column_commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    )
+}
+
+#[test]
+fn cells_verify_against_column_commitments() {
+    let fixture = Fixture::random(ROWS, COLS, 11);
+    let cells: Vec<(usize, usize)> = (0..ROWS * 2).flat_map(|row| (0..COLS).map(move |col| (row, col))).collect();
+
+    assert_eq!(verify_columns(&fixture, &cells, &fixture.column_proofs(&cells)) as usize, cells.len());
+
+    // row proofs don't open column commitments & vice versa
+    let sampled = [(1, 2), (6, 5)];
+    assert_eq!(verify_columns(&fixture, &sampled, &fixture.proofs(&sampled)), 0);
+    assert_eq!(verify(&fixture, &sampled, &fixture.column_proofs(&sampled)), 0);
+
+    // column beyond committed ones
+    assert_eq!(verify_columns(&fixture, &[(0, COLS)], &fixture.column_proof(0, 0)), 0);
+}
+
+#[test]
+fn unknown_scheme_is_rejected() {
+    let fixture = Fixture::random(ROWS, COLS, 12);
+    let (rows, cols) = ([0u8], [0u8]);
+    let proof = fixture.proof(0, 0);
+
+    let verify_with = |scheme: u8| {
+        verifier::verify_proof_with_scheme(
+            scheme,
+            1,
+            fixture.total_rows,
+            fixture.total_cols,
+            rows.as_ptr(),
+            rows.len(),
+            cols.as_ptr(),
+            cols.len(),
+            fixture.commitment.as_ptr(),
+            fixture.commitment.len(),
+            proof.as_ptr(),
+            proof.len(),
+        )
+    };
+
+    assert_eq!(verify_with(0), 1);
+    assert_eq!(verify_with(2), 0);
+}
+
+// Verifies multiproof of cells of one row, through C ABI
+fn verify_multi(fixture: &Fixture, row: u16, cols: &[u16], proof: &[u8]) -> i8 {
+    verifier::verify_multiproof(
+        1,
+        fixture.total_cols,
+        row,
+        cols.as_ptr(),
+        cols.len(),
+        fixture.commitment.as_ptr(),
+        fixture.commitment.len(),
+        proof.as_ptr(),
+        proof.len(),
+    )
+}
+
+#[test]
+fn multiproof_of_row_range_verifies() {
+    let fixture = Fixture::random(ROWS, COLS, 13);
+
+    for (row, cols) in [(0u16, vec![0u16]), (3, vec![2, 3, 4, 5]), (6, vec![7, 0, 4]), (7, (0..COLS as u16).collect())].iter() {
+        let as_usize: Vec<usize> = cols.iter().map(|&col| col as usize).collect();
+        let proof = fixture.multiproof(*row as usize, &as_usize);
+        assert_eq!(proof.len(), 96 + cols.len() * 32);
+
+        // data chunks follow witnesses, in order of asked columns
+        for (i, &col) in as_usize.iter().enumerate() {
+            assert_eq!(&proof[96 + i * 32..96 + (i + 1) * 32], &fixture.cell_data(*row as usize, col)[..]);
+        }
+
+        assert_eq!(verify_multi(&fixture, *row, cols, &proof), 1);
+    }
+}
+
+#[test]
+fn tampered_multiproofs_fail() {
+    let fixture = Fixture::random(ROWS, COLS, 14);
+    let cols = [1u16, 2, 3];
+    let proof = fixture.multiproof(2, &[1, 2, 3]);
+
+    // one evaluation swapped with some other value
+    let mut tampered = proof.clone();
+    tampered[96 + 32..96 + 64].copy_from_slice(&BlsScalar::from(42).to_bytes());
+    assert_eq!(verify_multi(&fixture, 2, &cols, &tampered), 0);
+
+    // columns asked in other order, than proof was built for
+    assert_eq!(verify_multi(&fixture, 2, &[3, 2, 1], &proof), 0);
+
+    // other row's commitment
+    assert_eq!(verify_multi(&fixture, 4, &cols, &proof), 0);
+
+    // malformed ones
+    assert_eq!(verify_multi(&fixture, 2, &cols, &proof[..proof.len() - 32]), -1);
+    assert_eq!(verify_multi(&fixture, 2, &[1, 1, 3], &proof), -1);
+    assert_eq!(verify_multi(&fixture, 2, &[1, 2, COLS as u16], &proof), -1);
+    assert_eq!(verify_multi(&fixture, ROWS as u16 * 2, &cols, &proof), -1);
+    assert_eq!(verify_multi(&fixture, 2, &[], &proof[..96]), -1);
+}